@@ -0,0 +1,97 @@
+//! Dataset asset resolution: exe-dir/env-override/repo-root search, or
+//! compile-time embedding behind the `embed-dataset` feature
+//!
+//! Resolving the dataset relative to the current working directory is
+//! fragile — it assumes CWD is the project root. `AssetSource` instead
+//! searches the running executable's directory, an env override, and the
+//! repo-root-relative default in order; or, when the `embed-dataset` feature
+//! is enabled, reads category JSON straight out of bytes embedded in the
+//! binary at compile time, so a single self-contained benchmark binary can
+//! ship with no external files.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Env var that overrides dataset resolution with an explicit path
+pub const DATASET_PATH_ENV: &str = "VEX_HALT_DATASET_PATH";
+
+#[cfg(feature = "embed-dataset")]
+static EMBEDDED_DATASET: include_dir::Dir<'_> =
+    include_dir::include_dir!("$CARGO_MANIFEST_DIR/datasets/vex_halt");
+
+/// Where the dataset's bytes are coming from
+pub enum AssetSource {
+    /// Read from a directory on disk
+    Directory(PathBuf),
+    /// Read from bytes embedded in the binary at compile time
+    #[cfg(feature = "embed-dataset")]
+    Embedded,
+}
+
+impl AssetSource {
+    /// Resolve the active asset source: embedded data when the
+    /// `embed-dataset` feature is enabled, else the executable's directory,
+    /// then `VEX_HALT_DATASET_PATH`, then `default_relative` as given (so
+    /// CWD-relative resolution still works when run from the project root).
+    pub fn resolve(default_relative: impl AsRef<Path>) -> Result<Self> {
+        #[cfg(feature = "embed-dataset")]
+        {
+            let _ = default_relative;
+            return Ok(AssetSource::Embedded);
+        }
+
+        #[cfg(not(feature = "embed-dataset"))]
+        {
+            let default_relative = default_relative.as_ref();
+
+            if let Ok(exe) = std::env::current_exe() {
+                if let Some(exe_dir) = exe.parent() {
+                    let candidate = exe_dir.join(default_relative);
+                    if candidate.exists() {
+                        return Ok(AssetSource::Directory(candidate));
+                    }
+                }
+            }
+
+            if let Ok(override_path) = std::env::var(DATASET_PATH_ENV) {
+                let candidate = PathBuf::from(override_path);
+                if candidate.exists() {
+                    return Ok(AssetSource::Directory(candidate));
+                }
+            }
+
+            if default_relative.exists() {
+                return Ok(AssetSource::Directory(default_relative.to_path_buf()));
+            }
+
+            bail!(
+                "Could not resolve dataset directory relative to the executable's directory, ${}, or {:?}",
+                DATASET_PATH_ENV,
+                default_relative
+            )
+        }
+    }
+
+    /// Read a dataset file's contents by its path relative to the dataset root
+    pub fn read_to_string(&self, relative_path: &str) -> Result<String> {
+        match self {
+            AssetSource::Directory(base) => std::fs::read_to_string(base.join(relative_path))
+                .with_context(|| format!("Failed to read dataset file {relative_path}")),
+            #[cfg(feature = "embed-dataset")]
+            AssetSource::Embedded => EMBEDDED_DATASET
+                .get_file(relative_path)
+                .and_then(|f| f.contents_utf8())
+                .map(|s| s.to_string())
+                .with_context(|| format!("Embedded dataset is missing file {relative_path}")),
+        }
+    }
+
+    /// Whether this source exposes `relative_path`
+    pub fn exists(&self, relative_path: &str) -> bool {
+        match self {
+            AssetSource::Directory(base) => base.join(relative_path).exists(),
+            #[cfg(feature = "embed-dataset")]
+            AssetSource::Embedded => EMBEDDED_DATASET.get_file(relative_path).is_some(),
+        }
+    }
+}
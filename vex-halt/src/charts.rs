@@ -0,0 +1,469 @@
+//! SVG chart rendering backed by `plotters`
+//!
+//! The HTML report used to build its charts by concatenating SVG markup by
+//! hand (`generate_cost_accuracy_chart` in `report.rs`), which made axis
+//! scaling, tick labeling, and adding new chart kinds fragile. This module
+//! builds charts with `plotters::prelude::SVGBackend` instead, reusing the
+//! GitHub-dark palette the rest of the report already uses, and exposes them
+//! all through one `render_chart` entry point.
+
+use crate::pricing::PricingTable;
+use crate::report::score_to_letter_grade;
+use crate::types::{BenchmarkResults, TestCategory};
+use anyhow::Result;
+use plotters::prelude::*;
+
+/// GitHub-dark palette shared with the rest of the HTML report
+const BACKGROUND: RGBColor = RGBColor(13, 17, 23);
+const GRID: RGBColor = RGBColor(33, 38, 45);
+const AXIS: RGBColor = RGBColor(139, 148, 158);
+const LABEL: RGBColor = RGBColor(201, 209, 217);
+const GRADE_A: RGBColor = RGBColor(63, 185, 80);
+const GRADE_B: RGBColor = RGBColor(88, 166, 255);
+const GRADE_C: RGBColor = RGBColor(210, 153, 34);
+const GRADE_F: RGBColor = RGBColor(248, 81, 73);
+
+fn grade_color(score: f64) -> RGBColor {
+    match score_to_letter_grade(score).as_str() {
+        "A+" | "A" => GRADE_A,
+        "B" => GRADE_B,
+        "C" => GRADE_C,
+        _ => GRADE_F,
+    }
+}
+
+/// Which of the report's built-in charts to render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    /// Scatter of category score (%) vs. estimated category cost
+    CostAccuracy,
+    /// Bar chart of score (%) per category
+    CategoryScores,
+    /// Histogram of per-test execution time (ms)
+    LatencyDistribution,
+    /// Per-category box-and-whisker plot of per-test execution time (ms)
+    LatencyBoxplot,
+}
+
+/// Render one of the report's charts to an embeddable SVG string
+pub fn render_chart(kind: ChartKind, results: &BenchmarkResults, pricing: &PricingTable) -> Result<String> {
+    match kind {
+        ChartKind::CostAccuracy => cost_accuracy_chart(results, pricing),
+        ChartKind::CategoryScores => category_scores_chart(results),
+        ChartKind::LatencyDistribution => latency_distribution_chart(results),
+        ChartKind::LatencyBoxplot => latency_boxplot_chart(results),
+    }
+}
+
+/// Five-number summary (min, Q1, median, Q3, max) of `samples`, computed by
+/// linear interpolation between ranks — Q1 at rank `(n-1) * 0.25`, etc.
+/// Whiskers extend to the furthest sample within 1.5x IQR of the quartiles;
+/// anything beyond that is returned in `outliers`. `samples` need not be
+/// sorted.
+struct FiveNumberSummary {
+    whisker_low: f64,
+    q1: f64,
+    median: f64,
+    q3: f64,
+    whisker_high: f64,
+    outliers: Vec<f64>,
+}
+
+fn five_number_summary(samples: &[f64]) -> FiveNumberSummary {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+
+    let percentile = |p: f64| -> f64 {
+        if n == 1 {
+            return sorted[0];
+        }
+        let rank = (n - 1) as f64 * p;
+        let lo = rank.floor() as usize;
+        let hi = rank.ceil() as usize;
+        if lo == hi {
+            sorted[lo]
+        } else {
+            sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+        }
+    };
+
+    let q1 = percentile(0.25);
+    let median = percentile(0.5);
+    let q3 = percentile(0.75);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+
+    let whisker_low = sorted
+        .iter()
+        .copied()
+        .filter(|v| *v >= lower_fence)
+        .fold(q1, f64::min);
+    let whisker_high = sorted
+        .iter()
+        .copied()
+        .filter(|v| *v <= upper_fence)
+        .fold(q3, f64::max);
+    let outliers = sorted
+        .iter()
+        .copied()
+        .filter(|v| *v < lower_fence || *v > upper_fence)
+        .collect();
+
+    FiveNumberSummary { whisker_low, q1, median, q3, whisker_high, outliers }
+}
+
+fn latency_boxplot_chart(results: &BenchmarkResults) -> Result<String> {
+    let series: Vec<(String, FiveNumberSummary)> = TestCategory::all()
+        .into_iter()
+        .filter_map(|cat| {
+            let result = results.categories.get(&cat)?;
+            let samples: Vec<f64> = result.test_results.iter().map(|t| t.execution_time_ms).collect();
+            if samples.is_empty() {
+                return None;
+            }
+            Some((cat.name().to_string(), five_number_summary(&samples)))
+        })
+        .collect();
+
+    let max_latency = series
+        .iter()
+        .flat_map(|(_, s)| std::iter::once(s.whisker_high).chain(s.outliers.iter().copied()))
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (800, 400)).into_drawing_area();
+        root.fill(&BACKGROUND)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(20)
+            .x_label_area_size(60)
+            .y_label_area_size(55)
+            .build_cartesian_2d(0usize..series.len().max(1), 0.0..max_latency * 1.1)?;
+
+        chart
+            .configure_mesh()
+            .axis_style(AXIS)
+            .bold_line_style(GRID)
+            .light_line_style(GRID.mix(0.3))
+            .label_style(("sans-serif", 12).into_font().color(&LABEL))
+            .disable_x_mesh()
+            .x_label_formatter(&|idx| series.get(*idx).map(|(n, _)| n.clone()).unwrap_or_default())
+            .y_desc("Execution time (ms)")
+            .draw()?;
+
+        for (idx, (_, summary)) in series.iter().enumerate() {
+            let quartiles = Quartiles::new(&[
+                summary.whisker_low,
+                summary.q1,
+                summary.median,
+                summary.q3,
+                summary.whisker_high,
+            ]);
+            chart.draw_series(std::iter::once(
+                Boxplot::new_vertical(idx, &quartiles).width(30).style(RGBColor(88, 166, 255)),
+            ))?;
+
+            for outlier in &summary.outliers {
+                chart.draw_series(std::iter::once(Circle::new(
+                    (idx, *outlier),
+                    3,
+                    RGBColor(248, 81, 73).filled(),
+                )))?;
+            }
+        }
+
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+/// Indices of `points` that sit on the cost/accuracy Pareto frontier —
+/// lower cost and higher score are both "better". Sorts by cost ascending
+/// (ties broken by score descending) and sweeps left to right: a point is
+/// on the frontier iff its score is strictly greater than the best score
+/// seen among all points with strictly lower cost (so ties in cost never
+/// count as "cheaper" than each other).
+fn pareto_frontier(points: &[(String, f64, f64)]) -> Vec<bool> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| {
+        points[a].1
+            .partial_cmp(&points[b].1)
+            .unwrap()
+            .then_with(|| points[b].2.partial_cmp(&points[a].2).unwrap())
+    });
+
+    let mut frontier = vec![false; points.len()];
+    let mut best_so_far = f64::NEG_INFINITY;
+    let mut i = 0;
+    while i < order.len() {
+        let cost_i = points[order[i]].1;
+        let mut j = i;
+        while j < order.len() && points[order[j]].1 == cost_i {
+            j += 1;
+        }
+        for &idx in &order[i..j] {
+            if points[idx].2 > best_so_far {
+                frontier[idx] = true;
+            }
+        }
+        let group_max = order[i..j].iter().map(|&idx| points[idx].2).fold(f64::NEG_INFINITY, f64::max);
+        best_so_far = best_so_far.max(group_max);
+        i = j;
+    }
+    frontier
+}
+
+/// Sample mean and standard error of the mean (sample std dev / sqrt(n)).
+/// SE is `0.0` for fewer than 2 samples — there's no dispersion to report.
+fn mean_and_standard_error(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len();
+    if n == 0 {
+        return (0.0, 0.0);
+    }
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    if n < 2 {
+        return (mean, 0.0);
+    }
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    (mean, variance.sqrt() / (n as f64).sqrt())
+}
+
+/// 95% confidence half-width from a standard error, via the usual
+/// large-sample normal approximation (1.96 SE)
+const CI_95_Z: f64 = 1.96;
+
+struct CategoryPoint {
+    label: String,
+    mean_cost: f64,
+    se_cost: f64,
+    mean_score: f64,
+    se_score: f64,
+}
+
+fn cost_accuracy_chart(results: &BenchmarkResults, pricing: &PricingTable) -> Result<String> {
+    let rate = pricing.rate(&results.provider);
+
+    let points: Vec<CategoryPoint> = results
+        .categories
+        .iter()
+        .map(|(cat, result)| {
+            let scores: Vec<f64> = result.test_results.iter().map(|t| t.score).collect();
+            let costs: Vec<f64> = result
+                .test_results
+                .iter()
+                .map(|t| t.token_usage.as_ref().map(|u| rate.cost(u)).unwrap_or(0.0))
+                .collect();
+            let (mean_score, se_score) = mean_and_standard_error(&scores);
+            let (mean_cost, se_cost) = mean_and_standard_error(&costs);
+            CategoryPoint { label: cat.name().to_string(), mean_cost, se_cost, mean_score, se_score }
+        })
+        .collect();
+
+    let frontier_points: Vec<(String, f64, f64)> =
+        points.iter().map(|p| (p.label.clone(), p.mean_cost, p.mean_score)).collect();
+    let on_frontier = pareto_frontier(&frontier_points);
+    let frontier_line: Vec<(f64, f64)> = points
+        .iter()
+        .zip(&on_frontier)
+        .filter(|(_, on)| **on)
+        .map(|(p, _)| (p.mean_cost, p.mean_score))
+        .collect();
+
+    let max_cost = points
+        .iter()
+        .map(|p| p.mean_cost + CI_95_Z * p.se_cost)
+        .fold(0.0_f64, f64::max)
+        .max(0.000_001);
+
+    // (pixel_x, pixel_y, label, mean_cost, se_cost, mean_score, se_score,
+    // on_frontier) for a tooltip overlay spliced into the SVG after
+    // plotters renders the mesh and points — the plotters SVG backend has
+    // no native hover/title API.
+    let mut tooltips: Vec<(i32, i32, String, f64, f64, f64, f64, bool)> = Vec::new();
+
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (800, 400)).into_drawing_area();
+        root.fill(&BACKGROUND)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(20)
+            .x_label_area_size(35)
+            .y_label_area_size(45)
+            .build_cartesian_2d(0.0..max_cost * 1.1, 0.0..100.0)?;
+
+        chart
+            .configure_mesh()
+            .axis_style(AXIS)
+            .bold_line_style(GRID)
+            .light_line_style(GRID.mix(0.3))
+            .label_style(("sans-serif", 12).into_font().color(&LABEL))
+            .x_desc(format!("Cost ({})", rate.currency))
+            .y_desc("Score (%)")
+            .draw()?;
+
+        if frontier_line.len() >= 2 {
+            chart.draw_series(std::iter::once(PathElement::new(frontier_line.clone(), GRADE_A.stroke_width(2))))?;
+        }
+
+        for (point, on) in points.iter().zip(&on_frontier) {
+            let alpha = if *on { 1.0 } else { 0.35 };
+            let color = grade_color(point.mean_score).mix(alpha);
+            let bar_style = color.stroke_width(1);
+
+            chart.draw_series(std::iter::once(ErrorBar::new_vertical(
+                point.mean_cost,
+                point.mean_score - CI_95_Z * point.se_score,
+                point.mean_score,
+                point.mean_score + CI_95_Z * point.se_score,
+                bar_style,
+                8,
+            )))?;
+            chart.draw_series(std::iter::once(ErrorBar::new_horizontal(
+                point.mean_score,
+                point.mean_cost - CI_95_Z * point.se_cost,
+                point.mean_cost,
+                point.mean_cost + CI_95_Z * point.se_cost,
+                bar_style,
+                8,
+            )))?;
+
+            chart.draw_series(std::iter::once(Circle::new((point.mean_cost, point.mean_score), 6, color.filled())))?;
+            chart.draw_series(std::iter::once(Text::new(
+                point.label.clone(),
+                (point.mean_cost, point.mean_score + 3.0),
+                ("sans-serif", 11).into_font().color(&LABEL.mix(if *on { 1.0 } else { 0.5 })),
+            )))?;
+
+            let (px, py) = chart.plotting_area().map_coordinate(&(point.mean_cost, point.mean_score));
+            tooltips.push((px, py, point.label.clone(), point.mean_cost, point.se_cost, point.mean_score, point.se_score, *on));
+        }
+
+        root.present()?;
+    }
+
+    // Splice in an invisible, hoverable circle with a `<title>` per point —
+    // cheaper than teaching the rendering pipeline about tooltips, and the
+    // original hand-rolled chart this replaced did the same thing.
+    let mut overlay = String::new();
+    for (px, py, label, mean_cost, se_cost, mean_score, se_score, on) in &tooltips {
+        let frontier_note = if *on { "Pareto-efficient" } else { "dominated" };
+        overlay.push_str(&format!(
+            r##"<circle cx="{}" cy="{}" r="8" fill="transparent"><title>{}: {:.1}% ± {:.1} / {}{:.5} ± {:.5} ({})</title></circle>"##,
+            px,
+            py,
+            label,
+            mean_score,
+            CI_95_Z * se_score,
+            currency_display(&rate.currency),
+            mean_cost,
+            CI_95_Z * se_cost,
+            frontier_note
+        ));
+    }
+    if let Some(pos) = svg.rfind("</svg>") {
+        svg.insert_str(pos, &overlay);
+    }
+
+    Ok(svg)
+}
+
+/// `$`-style prefix for a currency code in inline SVG text, mirroring
+/// `report::currency_symbol` without taking a dependency the other way
+fn currency_display(currency: &str) -> String {
+    match currency {
+        "USD" => "$".to_string(),
+        other => format!("{} ", other),
+    }
+}
+
+fn category_scores_chart(results: &BenchmarkResults) -> Result<String> {
+    let bars: Vec<(String, f64)> = TestCategory::all()
+        .into_iter()
+        .filter_map(|cat| results.categories.get(&cat).map(|r| (cat.name().to_string(), r.score)))
+        .collect();
+
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (800, 400)).into_drawing_area();
+        root.fill(&BACKGROUND)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(20)
+            .x_label_area_size(60)
+            .y_label_area_size(45)
+            .build_cartesian_2d(0usize..bars.len().max(1), 0.0..100.0)?;
+
+        chart
+            .configure_mesh()
+            .axis_style(AXIS)
+            .bold_line_style(GRID)
+            .light_line_style(GRID.mix(0.3))
+            .label_style(("sans-serif", 12).into_font().color(&LABEL))
+            .disable_x_mesh()
+            .x_label_formatter(&|idx| bars.get(*idx).map(|(n, _)| n.clone()).unwrap_or_default())
+            .y_desc("Score (%)")
+            .draw()?;
+
+        chart.draw_series(bars.iter().enumerate().map(|(idx, (_, score))| {
+            Rectangle::new([(idx, 0.0), (idx + 1, *score)], grade_color(*score).filled())
+        }))?;
+
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+fn latency_distribution_chart(results: &BenchmarkResults) -> Result<String> {
+    let latencies: Vec<f64> = results
+        .categories
+        .values()
+        .flat_map(|c| c.test_results.iter())
+        .map(|t| t.execution_time_ms)
+        .collect();
+
+    let max_latency = latencies.iter().cloned().fold(0.0_f64, f64::max).max(1.0);
+    let bucket_count = 20usize;
+    let bucket_width = max_latency / bucket_count as f64;
+    let mut buckets = vec![0usize; bucket_count];
+    for latency in &latencies {
+        let idx = ((*latency / bucket_width) as usize).min(bucket_count - 1);
+        buckets[idx] += 1;
+    }
+    let max_count = buckets.iter().copied().max().unwrap_or(0).max(1) as f64;
+
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (800, 400)).into_drawing_area();
+        root.fill(&BACKGROUND)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(20)
+            .x_label_area_size(35)
+            .y_label_area_size(45)
+            .build_cartesian_2d(0.0..max_latency, 0.0..max_count * 1.1)?;
+
+        chart
+            .configure_mesh()
+            .axis_style(AXIS)
+            .bold_line_style(GRID)
+            .light_line_style(GRID.mix(0.3))
+            .label_style(("sans-serif", 12).into_font().color(&LABEL))
+            .x_desc("Execution time (ms)")
+            .y_desc("Test count")
+            .draw()?;
+
+        chart.draw_series(buckets.iter().enumerate().map(|(idx, count)| {
+            let x0 = idx as f64 * bucket_width;
+            let x1 = x0 + bucket_width;
+            Rectangle::new([(x0, 0.0), (x1, *count as f64)], RGBColor(88, 166, 255).filled())
+        }))?;
+
+        root.present()?;
+    }
+    Ok(svg)
+}
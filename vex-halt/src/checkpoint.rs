@@ -0,0 +1,71 @@
+//! On-disk checkpointing for resumable benchmark runs
+//!
+//! Large provider runs (Claude/OpenAI/Gemini) can take a long time and die
+//! partway through. `BenchmarkRunner::execute_tests` periodically persists
+//! completed `TestResult`s here, keyed by `TestItem.id`, and on a `--resume`
+//! run skips any item whose result is already checkpointed and still
+//! hash-consistent with the item's current prompt (so an edited dataset
+//! invalidates the stale entry instead of silently reusing it). `--force`
+//! ignores an existing checkpoint and reruns everything from scratch.
+
+use crate::merkle::hash_data;
+use crate::types::{TestItem, TestResult};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// One checkpointed item: its result plus a hash of the prompt it was run
+/// against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointEntry {
+    pub result: TestResult,
+    pub prompt_hash: String,
+}
+
+/// On-disk checkpoint state: completed results keyed by `TestItem.id`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub entries: HashMap<String, CheckpointEntry>,
+}
+
+impl Checkpoint {
+    /// Load a checkpoint file, if it exists. An empty checkpoint is
+    /// returned when the path doesn't exist yet, so a first run and a
+    /// resumed run can share the same call site.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read checkpoint file {:?}", path))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse checkpoint file {:?}", path))
+    }
+
+    /// Persist the checkpoint to `path`, overwriting any existing file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write checkpoint file {:?}", path))
+    }
+
+    /// Record (or replace) one item's result
+    pub fn record(&mut self, item: &TestItem, result: TestResult) {
+        self.entries.insert(
+            item.id.clone(),
+            CheckpointEntry { result, prompt_hash: hash_data(&item.prompt) },
+        );
+    }
+
+    /// The checkpointed result for `item`, if present and still
+    /// hash-consistent with the item's current prompt
+    pub fn get(&self, item: &TestItem) -> Option<&TestResult> {
+        let entry = self.entries.get(&item.id)?;
+        if entry.prompt_hash == hash_data(&item.prompt) {
+            Some(&entry.result)
+        } else {
+            None
+        }
+    }
+}
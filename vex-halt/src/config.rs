@@ -3,10 +3,13 @@
 #![allow(dead_code)]  // verbose field for future CLI enhancement
 
 use crate::types::{BenchmarkMode, OutputFormat, ProviderType};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 /// Benchmark configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BenchmarkConfig {
     /// Benchmark mode (baseline, vex, compare)
     pub mode: BenchmarkMode,
@@ -43,6 +46,120 @@ pub struct BenchmarkConfig {
 
     /// Dry run (validate dataset without API calls)
     pub dry_run: bool,
+
+    /// Path to a baseline-expectations JSON file (see `crate::expectations`).
+    /// When set, each result is classified against its recorded expectation
+    /// and a regression (an item that was passing and now fails) causes the
+    /// process to exit non-zero.
+    pub expectations_path: Option<PathBuf>,
+
+    /// Number of times to rerun an item whose result disagrees with its
+    /// recorded expectation before classifying it as a regression. If the
+    /// item passes on some reruns and fails on others, it's marked `Flake`
+    /// and excluded from the regression gate.
+    pub max_flake_reruns: usize,
+
+    /// Number of items to generate/evaluate concurrently in `execute_tests`
+    pub parallelism: NonZeroUsize,
+
+    /// Seed for deterministic item-execution ordering (and any future VEX
+    /// sampling). `None` runs items in dataset order.
+    pub seed: Option<u64>,
+
+    /// Directory to persist each run's `BenchmarkResults` into (see
+    /// `crate::history`). `None` disables history persistence.
+    pub history_dir: Option<PathBuf>,
+
+    /// `--baseline <path-or-latest>`: a prior run (a specific history file,
+    /// or `"latest"` for the most recent one in `history_dir`) to diff this
+    /// run's per-category scores against
+    pub baseline: Option<String>,
+
+    /// `--shard k/n`: run only the k-th of n deterministic slices of the
+    /// dataset (see `crate::planner`), so a distributed run can split the
+    /// suite across workers while staying reproducible. `None` runs the
+    /// whole dataset.
+    pub shard: Option<(usize, usize)>,
+
+    /// Path to a checkpoint file (see `crate::checkpoint`) that
+    /// `execute_tests` periodically writes completed results to, so a dead
+    /// run can pick back up instead of starting over. `None` disables
+    /// checkpointing.
+    pub checkpoint_path: Option<PathBuf>,
+
+    /// `--resume`: skip any item whose result is already in the checkpoint
+    /// file and still hash-consistent with its current prompt
+    pub resume: bool,
+
+    /// `--force`: ignore an existing checkpoint file and rerun every item,
+    /// overwriting stale entries as fresh results complete
+    pub force: bool,
+
+    /// Path to a JSON pricing-overrides file (see `crate::pricing`). Entries
+    /// not present in the file fall back to `PricingTable::defaults()`.
+    /// `None` uses the built-in defaults only.
+    pub pricing_config: Option<PathBuf>,
+
+    /// Extra Wycheproof-style grouped test-vector files (see `crate::vectors`)
+    /// whose items are appended to the dataset after `DatasetLoader::load_all`,
+    /// letting users layer a hand-authored, versioned JSON dataset on top of
+    /// the built-in categories without writing Rust.
+    pub vector_paths: Vec<PathBuf>,
+
+    /// Path to a JSON `{"CATEGORY": reliability, ...}` file (see
+    /// `crate::scoring::load_reliability_table`, produced by
+    /// `--calibrate-judge`) used to widen each category's score confidence
+    /// interval by how trustworthy its judge has been shown to be.
+    /// Categories absent from the file, or `None` here, keep full trust
+    /// (`judge_reliability = 1.0`).
+    pub judge_reliability_path: Option<PathBuf>,
+
+    /// Directory for resumable, tamper-evident debate-round persistence
+    /// (see `crate::debate_store::FileDebateStore`). When set, each item's
+    /// VEX debate (see `crate::vex_integration::verify_with_vex`) is
+    /// checkpointed round-by-round under this directory, keyed by item id,
+    /// so a dead run can pick back up mid-debate instead of re-querying
+    /// from round zero. `None` disables debate persistence.
+    pub debate_store_dir: Option<PathBuf>,
+
+    /// `--filter-id`: glob (`*`) or substring pattern against `TestItem::id`
+    /// (see `crate::dataset::ItemFilter::id_pattern`). `None` imposes no
+    /// constraint.
+    pub filter_id_pattern: Option<String>,
+
+    /// `--filter-subcategory`: allowlist of `TestItem::subcategory` values to
+    /// keep (see `crate::dataset::ItemFilter::subcategories`). `None`
+    /// imposes no constraint.
+    pub filter_subcategories: Option<Vec<String>>,
+
+    /// `--import-dataset`: load test items straight from a canonical JSONL
+    /// file written by `--export-dataset` (see
+    /// `crate::dataset::DatasetLoader::load_canonical`) instead of parsing
+    /// the `dataset_path` directory tree. `None` loads from `dataset_path`
+    /// as usual.
+    pub import_dataset_path: Option<PathBuf>,
+
+    /// `--enable-llm-judge`: also score subjective categories (EAS, MEM,
+    /// AGT, VSM, VEX) by consulting `--provider` through
+    /// `crate::llm_judge::run_jury`, instead of relying on rubric/pattern
+    /// matching alone. Off by default: it multiplies (by
+    /// `judge_jury_size`) the provider calls spent on every judged item.
+    pub enable_llm_judge: bool,
+
+    /// `--judge-jury-size`: number of independent judge calls
+    /// `crate::llm_judge::run_jury` aggregates per item when
+    /// `enable_llm_judge` is set.
+    pub judge_jury_size: usize,
+
+    /// `--judge-minimum-confidence`: qualified-majority agreement threshold
+    /// passed to `crate::llm_judge::run_jury` (see `llm_judge::Decision`).
+    pub judge_minimum_confidence: f64,
+
+    /// `--rubric-config`: path to a JSON file of rubric/red-flag overrides
+    /// loaded into a `crate::llm_judge::RubricRegistry` (see
+    /// `RubricRegistry::load`). `None` uses the built-in rubrics/red-flags
+    /// for every category.
+    pub rubric_config_path: Option<PathBuf>,
 }
 
 impl Default for BenchmarkConfig {
@@ -60,14 +177,80 @@ impl Default for BenchmarkConfig {
             debate_rounds: 3,
             lite_mode: false,
             dry_run: false,
+            expectations_path: None,
+            max_flake_reruns: 0,
+            parallelism: NonZeroUsize::new(5).unwrap(),
+            seed: None,
+            history_dir: None,
+            baseline: None,
+            shard: None,
+            checkpoint_path: None,
+            resume: false,
+            force: false,
+            pricing_config: None,
+            vector_paths: Vec::new(),
+            judge_reliability_path: None,
+            debate_store_dir: None,
+            filter_id_pattern: None,
+            filter_subcategories: None,
+            import_dataset_path: None,
+            enable_llm_judge: false,
+            judge_jury_size: 3,
+            judge_minimum_confidence: crate::llm_judge::DEFAULT_MINIMUM_CONFIDENCE,
+            rubric_config_path: None,
+        }
+    }
+}
+
+/// Token-bucket description for a provider's client-side rate limit: the
+/// bucket starts full at `max_burst` tokens, each request removes one, and
+/// tokens refill continuously at `refill_per_sec` (capped at `max_burst`).
+/// See `provider::TokenBucket` for the actual reserve/refill arithmetic.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimiterConfig {
+    pub max_burst: u32,
+    pub refill_per_sec: f64,
+}
+
+/// Retry/backoff policy for transient provider errors (HTTP 429/500/502/503
+/// and timeouts), plus a circuit breaker: once `terminate_after` requests in
+/// a row exhaust their retries, `provider::with_retry` reports a distinct
+/// circuit-breaker error instead of the usual transient failure, so a dead
+/// endpoint aborts the run rather than burning the full dataset one timeout
+/// at a time. Mirrors the "slow-timeout + terminate-after N" pattern nextest
+/// uses to kill hung tests. Each retry sleeps `backoff_base_ms * 2^attempt`
+/// plus jitter; the consecutive-failure count resets on any success.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub backoff_base_ms: u64,
+    pub terminate_after: u32,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            backoff_base_ms: 1000,
+            terminate_after: 5,
         }
     }
 }
 
 /// Provider-specific configuration
-#[derive(Debug, Clone)]
+///
+/// Deliberately `Serialize`-only, not `Deserialize`: every secret field below
+/// is tagged `#[serde(skip)]` so dumping a resolved config for logging/debug
+/// never writes a credential out, but loading one back in from a file isn't
+/// meaningful either, since those fields are always resolved from
+/// environment variables by the named constructors (`mistral()`, `openai()`,
+/// ...). A file-loadable run definition instead describes a `ProviderType`
+/// plus a small set of `ProviderOverrides` layered onto that provider's named
+/// constructor — see `load_run_config`.
+#[derive(Debug, Clone, Serialize)]
 pub struct ProviderConfig {
     /// API key (from environment)
+    #[serde(skip)]
     pub api_key: Option<String>,
     /// Model name
     pub model: String,
@@ -77,6 +260,37 @@ pub struct ProviderConfig {
     pub max_tokens: usize,
     /// Request timeout in seconds
     pub timeout_secs: u64,
+    /// AWS secret access key, used only by the Bedrock provider's SigV4 signing
+    #[serde(skip)]
+    pub aws_secret_key: Option<String>,
+    /// AWS region, used only by the Bedrock provider
+    pub aws_region: Option<String>,
+    /// GCP project id, used only by the Vertex AI provider
+    pub gcp_project: Option<String>,
+    /// GCP location/region (e.g. "us-central1"), used only by the Vertex AI provider
+    pub gcp_location: Option<String>,
+    /// Path to a service-account ADC JSON key file, used only by the Vertex AI provider
+    #[serde(skip)]
+    pub gcp_adc_path: Option<String>,
+    /// Base URL for OpenAI-compatible chat-completions endpoints (Ollama,
+    /// LocalAI, OpenRouter, Together, Groq, ...), used only by
+    /// `OpenAICompatibleProvider`
+    pub api_base: Option<String>,
+    /// Auth header name to send the API key under, used only by
+    /// `OpenAICompatibleProvider` (defaults to `"Authorization"` with a
+    /// `Bearer ` prefix; some gateways expect a bare API-key header instead)
+    pub auth_header: Option<String>,
+    /// Client-side request rate limit, enforced via a token-bucket before
+    /// each `generate`/`generate_stream` call. `None` means unlimited.
+    pub rate_limit: Option<RateLimiterConfig>,
+    /// Shared HS256 secret for authenticating to a gateway-fronted local LLM
+    /// endpoint, used only by `LocalProvider`. When set, each request mints
+    /// (and caches) a short-lived bearer JWT signed with this secret.
+    #[serde(skip)]
+    pub local_gateway_secret: Option<String>,
+    /// Retry/backoff and circuit-breaker policy, applied by
+    /// `provider::with_retry` around every `generate`/`generate_stream` call.
+    pub retry: RetryConfig,
 }
 
 impl ProviderConfig {
@@ -88,6 +302,16 @@ impl ProviderConfig {
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig { max_burst: 5, refill_per_sec: 1.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
@@ -99,6 +323,18 @@ impl ProviderConfig {
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 300,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            // DeepSeek's 300s timeout pairs with a slower refill — requests
+            // here are expected to take a while, not fire in a burst.
+            rate_limit: Some(RateLimiterConfig { max_burst: 3, refill_per_sec: 0.5 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
@@ -110,6 +346,16 @@ impl ProviderConfig {
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig { max_burst: 10, refill_per_sec: 5.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
@@ -121,6 +367,16 @@ impl ProviderConfig {
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig { max_burst: 10, refill_per_sec: 5.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
@@ -132,17 +388,171 @@ impl ProviderConfig {
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig {
+                max_burst: std::env::var("GEMINI_MAX_BURST").ok().and_then(|v| v.parse().ok()).unwrap_or(10),
+                refill_per_sec: std::env::var("GEMINI_MAX_RPS").ok().and_then(|v| v.parse().ok()).unwrap_or(5.0),
+            }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
-    /// Create config for Local llama.cpp server
+    /// Create config for Local llama.cpp server (or, with the `llama_cpp`
+    /// feature enabled, in-process GGUF inference, in which case `model` is
+    /// read from `LOCAL_MODEL_PATH` and used as the `.gguf` file path).
+    /// Kept distinct from `openai_compatible`/`openai_compatible_at` rather
+    /// than collapsed into one of them: `LocalProvider` also mints and
+    /// caches short-lived gateway bearer tokens (`local_gateway_secret`)
+    /// and the `llama_cpp` feature bypasses HTTP entirely, neither of
+    /// which the generic OpenAI-compatible path models.
     pub fn local() -> Self {
         Self {
             api_key: None, // Local server doesn't need API key
-            model: "local-model".to_string(), // Will be overridden by server
+            model: std::env::var("LOCAL_MODEL_PATH").unwrap_or_else(|_| "local-model".to_string()), // Will be overridden by server if not a real path
             temperature: 0.7,
             max_tokens: 2048,
             timeout_secs: 120, // Local models can be slower
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig {
+                max_burst: std::env::var("LOCAL_MAX_BURST").ok().and_then(|v| v.parse().ok()).unwrap_or(20),
+                refill_per_sec: std::env::var("LOCAL_MAX_RPS").ok().and_then(|v| v.parse().ok()).unwrap_or(10.0),
+            }),
+            local_gateway_secret: std::env::var("LOCAL_GATEWAY_SECRET").ok(),
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Create config for Bedrock (AWS-hosted Claude/Llama/Titan models)
+    pub fn bedrock() -> Self {
+        Self {
+            api_key: std::env::var("AWS_ACCESS_KEY_ID").ok(),
+            model: std::env::var("BEDROCK_MODEL_ID")
+                .unwrap_or_else(|_| "anthropic.claude-3-5-sonnet-20241022-v2:0".to_string()),
+            temperature: 0.7,
+            max_tokens: 2048,
+            timeout_secs: 60,
+            aws_secret_key: std::env::var("AWS_SECRET_ACCESS_KEY").ok(),
+            aws_region: Some(std::env::var("AWS_REGION").unwrap_or_else(|_| "us-east-1".to_string())),
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            // Bedrock's default per-account TPS quota is modest until raised
+            rate_limit: Some(RateLimiterConfig { max_burst: 5, refill_per_sec: 2.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Create config for Vertex AI (Gemini via a GCP project's own quota,
+    /// authenticated with service-account ADC instead of a raw API key)
+    pub fn vertex_ai() -> Self {
+        Self {
+            api_key: None, // Vertex AI authenticates via an ADC-derived bearer token, not an API key
+            model: "gemini-2.0-flash-exp".to_string(),
+            temperature: 0.7,
+            max_tokens: 2048,
+            timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: std::env::var("GOOGLE_CLOUD_PROJECT").ok(),
+            gcp_location: Some(std::env::var("GOOGLE_CLOUD_LOCATION").unwrap_or_else(|_| "us-central1".to_string())),
+            gcp_adc_path: std::env::var("GOOGLE_APPLICATION_CREDENTIALS").ok(),
+            api_base: None,
+            auth_header: None,
+            rate_limit: Some(RateLimiterConfig { max_burst: 5, refill_per_sec: 2.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Create config for Replicate (community-hosted open models, identified
+    /// by a `{owner}/{model}` slug in `model`)
+    pub fn replicate() -> Self {
+        Self {
+            api_key: std::env::var("REPLICATE_API_TOKEN").ok(),
+            model: std::env::var("REPLICATE_MODEL").unwrap_or_else(|_| "meta/meta-llama-3-70b-instruct".to_string()),
+            temperature: 0.7,
+            max_tokens: 2048,
+            timeout_secs: 300, // Replicate predictions are polled and can take a while to start
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            // Predictions are polled rather than fired in a burst
+            rate_limit: Some(RateLimiterConfig { max_burst: 3, refill_per_sec: 1.0 }),
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Create config for a generic OpenAI-compatible endpoint (Ollama,
+    /// LocalAI, OpenRouter, Together, Groq, or any other drop-in
+    /// chat-completions gateway), pointed at by `OPENAI_COMPATIBLE_API_BASE`
+    pub fn openai_compatible() -> Self {
+        Self {
+            api_key: std::env::var("OPENAI_COMPATIBLE_API_KEY").ok(),
+            model: std::env::var("OPENAI_COMPATIBLE_MODEL").unwrap_or_else(|_| "llama3".to_string()),
+            temperature: 0.7,
+            max_tokens: 2048,
+            timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: Some(std::env::var("OPENAI_COMPATIBLE_API_BASE").unwrap_or_else(|_| "http://localhost:11434/v1".to_string())),
+            auth_header: std::env::var("OPENAI_COMPATIBLE_AUTH_HEADER").ok(),
+            rate_limit: None, // self-hosted/local gateways aren't rate-limited by default
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Create config for an OpenAI-compatible endpoint with an explicit
+    /// base URL and model rather than `openai_compatible`'s env vars — the
+    /// one-liner for pointing at Azure OpenAI, Ollama's `/v1` endpoint, or
+    /// any other local/self-hosted gateway without exporting env vars
+    /// first. `api_key_env` is looked up the same way every other
+    /// constructor here looks up its vendor's key.
+    pub fn openai_compatible_at(
+        api_base: impl Into<String>,
+        model: impl Into<String>,
+        api_key_env: &str,
+    ) -> Self {
+        Self {
+            api_key: std::env::var(api_key_env).ok(),
+            model: model.into(),
+            temperature: 0.7,
+            max_tokens: 2048,
+            timeout_secs: 60,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: Some(api_base.into()),
+            auth_header: None,
+            rate_limit: None, // self-hosted/local gateways aren't rate-limited by default
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
         }
     }
 
@@ -154,12 +564,159 @@ impl ProviderConfig {
             temperature: 0.0,
             max_tokens: 2048,
             timeout_secs: 1,
+            aws_secret_key: None,
+            aws_region: None,
+            gcp_project: None,
+            gcp_location: None,
+            gcp_adc_path: None,
+            api_base: None,
+            auth_header: None,
+            rate_limit: None, // no real network calls to throttle
+            local_gateway_secret: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// The named constructor for `provider_type`'s defaults (API keys and
+    /// other secrets resolved from environment variables, everything else a
+    /// sensible per-vendor default). Used both by `provider::create_provider`
+    /// and as the base a file-loaded `ProviderOverrides` is applied onto.
+    pub fn for_provider_type(provider_type: ProviderType) -> Self {
+        match provider_type {
+            ProviderType::Mock => Self::mock(),
+            ProviderType::Mistral => Self::mistral(),
+            ProviderType::DeepSeek => Self::deepseek(),
+            ProviderType::OpenAI => Self::openai(),
+            ProviderType::Claude => Self::claude(),
+            ProviderType::Gemini => Self::gemini(),
+            ProviderType::Local => Self::local(),
+            ProviderType::Bedrock => Self::bedrock(),
+            ProviderType::VertexAi => Self::vertex_ai(),
+            ProviderType::Replicate => Self::replicate(),
+            ProviderType::OpenAICompatible => Self::openai_compatible(),
         }
     }
 }
 
+/// File-loadable overrides for `ProviderConfig`, applied on top of
+/// `ProviderConfig::for_provider_type`'s defaults. Only the knobs worth
+/// sharing in a reproducible run definition are here: no API keys or other
+/// secrets, which stay resolved from environment variables no matter what a
+/// file says (see `ProviderConfig`'s doc comment).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProviderOverrides {
+    pub model: Option<String>,
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<usize>,
+    pub timeout_secs: Option<u64>,
+    pub api_base: Option<String>,
+    pub auth_header: Option<String>,
+    pub rate_limit: Option<RateLimiterConfig>,
+    pub retry: Option<RetryConfig>,
+}
+
+impl ProviderOverrides {
+    /// Apply these overrides onto `base`, leaving any field not mentioned
+    /// untouched
+    pub fn apply(self, mut base: ProviderConfig) -> ProviderConfig {
+        if let Some(model) = self.model {
+            base.model = model;
+        }
+        if let Some(temperature) = self.temperature {
+            base.temperature = temperature;
+        }
+        if let Some(max_tokens) = self.max_tokens {
+            base.max_tokens = max_tokens;
+        }
+        if let Some(timeout_secs) = self.timeout_secs {
+            base.timeout_secs = timeout_secs;
+        }
+        if let Some(api_base) = self.api_base {
+            base.api_base = Some(api_base);
+        }
+        if let Some(auth_header) = self.auth_header {
+            base.auth_header = Some(auth_header);
+        }
+        if let Some(rate_limit) = self.rate_limit {
+            base.rate_limit = Some(rate_limit);
+        }
+        if let Some(retry) = self.retry {
+            base.retry = retry;
+        }
+        base
+    }
+}
+
+/// A shareable run definition loaded from a `.toml` or `.json` file (e.g.
+/// `vex-halt.toml`) via `load_run_config`. Mirrors `BenchmarkConfig` (itself
+/// directly `Deserialize`) plus a `[provider_overrides]` block and the VEX
+/// debate settings, so a whole benchmark run — mode, provider, rate limits,
+/// category filters, debate protocol — is reproducible and diffable from one
+/// file, without ever naming an API key.
+///
+/// The `vex` block is parsed but `resolve` does not return it; the debate
+/// settings a run actually uses are the flattened `enable_vex` and
+/// `debate_rounds` fields of `benchmark`.
+///
+/// The overrides block is `[provider_overrides]`, not `[provider]`: the flattened
+/// `benchmark.provider` field already owns the top-level `provider` key, and a
+/// second field mapped to the same key would collide.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunConfigFile {
+    #[serde(flatten)]
+    pub benchmark: BenchmarkConfig,
+    pub provider_overrides: ProviderOverrides,
+    pub vex: VexConfig,
+}
+
+impl RunConfigFile {
+    /// Parse a run definition from `path`: `.json` is parsed as JSON,
+    /// anything else (including the conventional `.toml`) as TOML.
+    pub fn load(path: &std::path::Path) -> anyhow::Result<Self> {
+        use anyhow::Context;
+
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {:?}", path))?;
+
+        let parsed: Self = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str(&raw)
+                .with_context(|| format!("Failed to parse JSON config {:?}", path))?,
+            _ => toml::from_str(&raw)
+                .with_context(|| format!("Failed to parse TOML config {:?}", path))?,
+        };
+
+        if let Some(retry) = parsed.provider_overrides.retry {
+            anyhow::ensure!(
+                retry.max_retries > 0,
+                "provider_overrides.retry.max_retries must be at least 1 (use 1 for \"no retries, exactly one attempt\"); got 0"
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Resolve this file into a runnable `(BenchmarkConfig, ProviderConfig)`
+    /// pair: the provider overrides are layered onto
+    /// `ProviderConfig::for_provider_type`'s defaults, which is where API
+    /// keys and other secrets actually get pulled from the environment.
+    pub fn resolve(self) -> (BenchmarkConfig, ProviderConfig) {
+        let provider_config =
+            self.provider_overrides.apply(ProviderConfig::for_provider_type(self.benchmark.provider));
+        (self.benchmark, provider_config)
+    }
+}
+
+/// Load a run definition from `path` and resolve it into a runnable
+/// `(BenchmarkConfig, ProviderConfig)` pair. See `RunConfigFile`.
+pub fn load_run_config(path: &std::path::Path) -> anyhow::Result<(BenchmarkConfig, ProviderConfig)> {
+    Ok(RunConfigFile::load(path)?.resolve())
+}
+
 /// VEX-specific configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct VexConfig {
     /// Number of debate rounds
     pub debate_rounds: usize,
@@ -169,8 +726,6 @@ pub struct VexConfig {
     pub enable_merkle: bool,
     /// Shadow agent intensity (0.0 - 1.0)
     pub shadow_intensity: f64,
-    /// Consensus protocol
-    pub consensus_protocol: ConsensusProtocol,
 }
 
 impl Default for VexConfig {
@@ -180,20 +735,6 @@ impl Default for VexConfig {
             confidence_threshold: 0.7,
             enable_merkle: true,
             shadow_intensity: 0.8,
-            consensus_protocol: ConsensusProtocol::WeightedConfidence,
         }
     }
 }
-
-/// Consensus protocol for VEX debate
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ConsensusProtocol {
-    /// Simple majority
-    Majority,
-    /// Two-thirds majority
-    SuperMajority,
-    /// Weighted by confidence scores
-    WeightedConfidence,
-    /// All must agree
-    Unanimous,
-}
@@ -14,13 +14,136 @@ pub struct DatasetLoader {
     base_path: std::path::PathBuf,
 }
 
-impl DatasetLoader {
-    pub fn new(base_path: impl AsRef<Path>) -> Self {
-        Self {
-            base_path: base_path.as_ref().to_path_buf(),
+/// One record `DatasetLoader::validate` declined to convert into a
+/// `TestItem`, and why. `id` is `None` when the record couldn't even be
+/// identified (e.g. malformed JSON, or missing the `id` field itself).
+#[derive(Debug, Clone)]
+pub struct SkippedItem {
+    pub file: std::path::PathBuf,
+    pub id: Option<String>,
+    pub reason: String,
+}
+
+/// Summary produced by `DatasetLoader::validate`: how many records loaded
+/// cleanly, and the full list of records that didn't.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub loaded: usize,
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// A comparison against one `TestItem::metadata` key, resolved against
+/// whatever `serde_json::Value` the dataset file happened to store there
+/// (e.g. `difficulty >= 4`, `severity == "high"`, `is_attack == true`).
+/// A predicate doesn't match if the key is absent or its value isn't
+/// comparable the requested way (e.g. `Ge` against a string).
+#[derive(Debug, Clone)]
+pub enum MetadataPredicate {
+    Eq(String, serde_json::Value),
+    Ge(String, f64),
+    Gt(String, f64),
+    Le(String, f64),
+    Lt(String, f64),
+}
+
+impl MetadataPredicate {
+    fn matches(&self, metadata: &HashMap<String, serde_json::Value>) -> bool {
+        match self {
+            MetadataPredicate::Eq(key, want) => match (metadata.get(key), want.as_f64()) {
+                (Some(got), Some(want_f64)) => got.as_f64().is_some_and(|got_f64| got_f64 == want_f64),
+                (got, _) => got == Some(want),
+            },
+            MetadataPredicate::Ge(key, want) => Self::as_f64(metadata, key).is_some_and(|v| v >= *want),
+            MetadataPredicate::Gt(key, want) => Self::as_f64(metadata, key).is_some_and(|v| v > *want),
+            MetadataPredicate::Le(key, want) => Self::as_f64(metadata, key).is_some_and(|v| v <= *want),
+            MetadataPredicate::Lt(key, want) => Self::as_f64(metadata, key).is_some_and(|v| v < *want),
         }
     }
 
+    fn as_f64(metadata: &HashMap<String, serde_json::Value>, key: &str) -> Option<f64> {
+        metadata.get(key).and_then(|v| v.as_f64())
+    }
+}
+
+/// Selects a subset of loaded `TestItem`s for `DatasetLoader::load_filtered`.
+/// An item is kept only if it satisfies every filter that's set — an unset
+/// filter (`None`, or an empty `metadata` list) imposes no constraint.
+#[derive(Debug, Clone, Default)]
+pub struct ItemFilter {
+    /// Glob (`*` wildcard) or, with no `*`, plain substring match against
+    /// `TestItem::id`.
+    pub id_pattern: Option<String>,
+    /// Allowlist of `TestItem::subcategory` values to keep.
+    pub subcategories: Option<Vec<String>>,
+    /// All of these must hold against `TestItem::metadata`.
+    pub metadata: Vec<MetadataPredicate>,
+}
+
+impl ItemFilter {
+    pub fn matches(&self, item: &TestItem) -> bool {
+        if let Some(pattern) = &self.id_pattern {
+            if !glob_match(pattern, &item.id) {
+                return false;
+            }
+        }
+
+        if let Some(subcategories) = &self.subcategories {
+            if !subcategories.iter().any(|s| s == &item.subcategory) {
+                return false;
+            }
+        }
+
+        self.metadata.iter().all(|p| p.matches(&item.metadata))
+    }
+}
+
+/// Match `text` against `pattern`, treating `*` as "zero or more
+/// characters"; a pattern with no `*` at all falls back to a plain
+/// substring match, so callers can write either a glob (`cct-hard-*`) or
+/// just a fragment (`hard`) without choosing a syntax up front. Reuses
+/// `evaluator::wildcard_matches`'s segment matcher rather than carrying a
+/// second copy of the same segment-matching logic.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    if !pattern.contains('*') {
+        return text.contains(pattern);
+    }
+
+    crate::evaluator::wildcard_matches(pattern, text, "*")
+}
+
+/// The generic-category loaders' `(dir_name, category, files)` triples,
+/// mirroring the `load_frontier`..`load_vex` wrapper methods below —
+/// kept in one place so `validate` doesn't drift from `load_all` if a
+/// category gains or loses a file.
+const GENERIC_CATEGORIES: &[(&str, TestCategory, &[&str])] = &[
+    ("frontier", TestCategory::FRONTIER, &["compositional_reasoning", "abstract_pattern", "research_math",
+                                            "meta_cognitive", "novel_generalization", "adversarial_reasoning"]),
+    ("vsm", TestCategory::VSM, &["confidence_misalignment"]),
+    ("mtc", TestCategory::MTC, &["tool_chains"]),
+    ("eas", TestCategory::EAS, &["uncertainty_classification"]),
+    ("mem", TestCategory::MEM, &["memory_evaluation", "episodic_recall", "temporal_decay", "compression"]),
+    ("agt", TestCategory::AGT, &["agentic_safety", "tool_use", "long_horizon"]),
+    ("vex", TestCategory::VEX, &["showcase", "ab_comparison"]),
+];
+
+impl DatasetLoader {
+    /// Resolve `default_relative` via `AssetSource::resolve` (executable's
+    /// directory, then `VEX_HALT_DATASET_PATH`, then `default_relative`
+    /// itself) rather than trusting the current working directory directly,
+    /// then build a loader rooted at whatever directory that finds.
+    pub fn new(default_relative: impl AsRef<Path>) -> Result<Self> {
+        let base_path = match crate::asset_source::AssetSource::resolve(default_relative)? {
+            crate::asset_source::AssetSource::Directory(dir) => dir,
+            #[cfg(feature = "embed-dataset")]
+            crate::asset_source::AssetSource::Embedded => anyhow::bail!(
+                "DatasetLoader does not support the embed-dataset feature's in-binary asset \
+                 source yet; build without --features embed-dataset"
+            ),
+        };
+
+        Ok(Self { base_path })
+    }
+
     /// Load all test items from the dataset
     pub async fn load_all(&self) -> Result<Vec<TestItem>> {
         let mut items = Vec::new();
@@ -45,6 +168,235 @@ impl DatasetLoader {
         Ok(items)
     }
 
+    /// Walk every category, attempting conversion of every record, and
+    /// report what was loaded and what was skipped instead of either
+    /// silently dropping bad records (the default lenient behavior of the
+    /// `.json`/`.jsonl` generic-category loaders) or aborting the whole run
+    /// on the first bad one (the legacy typed loaders' `?`-propagating
+    /// behavior). The legacy loaders parse and convert every record as part
+    /// of reading their file, and bail via `?` on the first bad one before
+    /// returning anything, so one bad record in (say) `cct/hard.json` is
+    /// reported as the whole `cct` category skipped, with `loaded` not
+    /// crediting the other, perfectly valid `cct/*.json` files — a known
+    /// granularity gap inherent to those loaders' all-or-nothing parsing,
+    /// not something `validate` can see past from the outside. The newer
+    /// generic-category loaders convert one record at a time, so those are
+    /// reported per-record instead.
+    ///
+    /// With `strict: true`, the first skipped record becomes a hard `Err`
+    /// instead of being added to the report, so CI can fail a build on a
+    /// malformed dataset.
+    pub async fn validate(&self, strict: bool) -> Result<ValidationReport> {
+        let mut report = ValidationReport::default();
+
+        macro_rules! validate_typed_category {
+            ($dir_name:literal, $loader:ident) => {
+                match self.$loader().await {
+                    Ok(items) => report.loaded += items.len(),
+                    Err(e) => {
+                        let skipped = SkippedItem {
+                            file: self.base_path.join($dir_name),
+                            id: None,
+                            reason: e.to_string(),
+                        };
+                        if strict {
+                            anyhow::bail!("Dataset validation failed for {:?}: {}", skipped.file, skipped.reason);
+                        }
+                        report.skipped.push(skipped);
+                    }
+                }
+            };
+        }
+
+        validate_typed_category!("cct", load_cct);
+        validate_typed_category!("api", load_api);
+        validate_typed_category!("fct", load_fct);
+        validate_typed_category!("hht", load_hht);
+        validate_typed_category!("rt", load_rt);
+
+        for &(dir_name, category, files) in GENERIC_CATEGORIES {
+            for &file in files {
+                self.validate_generic_file(dir_name, category, file, strict, &mut report).await?;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Validate one category file (preferring `<file>.jsonl` over
+    /// `<file>.json`, same as `load_generic_category`), recording a
+    /// `SkippedItem` (or, under `strict`, returning an `Err`) for every
+    /// record `generic_to_test_item_checked` rejects
+    async fn validate_generic_file(
+        &self,
+        dir_name: &str,
+        category: TestCategory,
+        file: &str,
+        strict: bool,
+        report: &mut ValidationReport,
+    ) -> Result<()> {
+        let cat_path = self.base_path.join(dir_name);
+
+        let jsonl_path = cat_path.join(format!("{}.jsonl", file));
+        if jsonl_path.exists() {
+            use tokio::io::AsyncBufReadExt;
+
+            let raw = tokio::fs::File::open(&jsonl_path).await
+                .context(format!("Failed to open {:?}", jsonl_path))?;
+            let mut lines = tokio::io::BufReader::new(raw).lines();
+            let mut line_no = 0usize;
+
+            while let Some(line) = lines.next_line().await
+                .context(format!("Failed to read {:?}", jsonl_path))?
+            {
+                line_no += 1;
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                let value: serde_json::Value = match serde_json::from_str(line) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        if strict {
+                            anyhow::bail!("Dataset validation failed for {:?} line {}: malformed JSON: {e}", jsonl_path, line_no);
+                        }
+                        report.skipped.push(SkippedItem {
+                            file: jsonl_path.clone(),
+                            id: None,
+                            reason: format!("line {}: malformed JSON: {e}", line_no),
+                        });
+                        continue;
+                    }
+                };
+
+                match generic_to_test_item_checked(&value, category, file) {
+                    Ok(_) => report.loaded += 1,
+                    Err(reason) => {
+                        let id = value.get("id").and_then(|v| v.as_str()).map(String::from);
+                        if strict {
+                            anyhow::bail!("Dataset validation failed for {:?} line {} (id={:?}): {}", jsonl_path, line_no, id, reason);
+                        }
+                        report.skipped.push(SkippedItem { file: jsonl_path.clone(), id, reason });
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        let path = cat_path.join(format!("{}.json", file));
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let content = tokio::fs::read_to_string(&path).await
+            .context(format!("Failed to read {:?}", path))?;
+        let parsed: serde_json::Value = serde_json::from_str(&content)
+            .context(format!("Failed to parse {:?}", path))?;
+
+        let test_items = parsed.get("problems")
+            .or_else(|| parsed.get("tests"))
+            .or_else(|| parsed.get("honeypots"))
+            .or_else(|| parsed.get("prompts"))
+            .or_else(|| parsed.get("questions"));
+
+        if let Some(serde_json::Value::Array(arr)) = test_items {
+            for item in arr {
+                match generic_to_test_item_checked(item, category, file) {
+                    Ok(_) => report.loaded += 1,
+                    Err(reason) => {
+                        let id = item.get("id").and_then(|v| v.as_str()).map(String::from);
+                        if strict {
+                            anyhow::bail!("Dataset validation failed for {:?} (id={:?}): {}", path, id, reason);
+                        }
+                        report.skipped.push(SkippedItem { file: path.clone(), id, reason });
+                    }
+                }
+            }
+        }
+
+        if let Some(serde_json::Value::Object(subcats)) = parsed.get("subcategories") {
+            for (subcat_name, subcat_data) in subcats {
+                if let Some(serde_json::Value::Array(tests)) = subcat_data.get("tests") {
+                    for item in tests {
+                        match generic_to_test_item_checked(item, category, subcat_name) {
+                            Ok(_) => report.loaded += 1,
+                            Err(reason) => {
+                                let id = item.get("id").and_then(|v| v.as_str()).map(String::from);
+                                if strict {
+                                    anyhow::bail!("Dataset validation failed for {:?} (id={:?}): {}", path, id, reason);
+                                }
+                                report.skipped.push(SkippedItem { file: path.clone(), id, reason });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load `categories` (or every category, if `None`) and keep only the
+    /// items `filter` matches — lets a caller run a focused sweep ("only
+    /// hard CCT math in the flawed-premise HHT bucket") without hand-editing
+    /// dataset files.
+    pub async fn load_filtered(
+        &self,
+        categories: Option<&[TestCategory]>,
+        filter: &ItemFilter,
+    ) -> Result<Vec<TestItem>> {
+        let items = match categories {
+            Some(categories) => self.load_categories(categories).await?,
+            None => self.load_all().await?,
+        };
+
+        Ok(items.into_iter().filter(|item| filter.matches(item)).collect())
+    }
+
+    /// Write `items` to `path` as one JSON-serialized `TestItem` per line —
+    /// a single canonical interchange format, independent of the many
+    /// ad-hoc per-category raw schemas (`CctFile`, `ApiFile`, the
+    /// `problems`/`tests`/`honeypots`/`subcategories` key-guessing in
+    /// `load_generic_category`). `items` is typically whatever a prior
+    /// `load_all`/`load_categories`/`load_filtered` call returned, so this
+    /// freezes a dataset snapshot (or a filtered subset of one) as a single
+    /// diffable, redistributable file that `load_canonical` can reload
+    /// exactly, without re-running any category-specific parsing.
+    pub async fn export_canonical(items: &[TestItem], path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut out = String::new();
+        for item in items {
+            out.push_str(&serde_json::to_string(item).context("Failed to serialize TestItem")?);
+            out.push('\n');
+        }
+
+        tokio::fs::write(path, out).await.context(format!("Failed to write {:?}", path))?;
+        Ok(())
+    }
+
+    /// Load a dataset previously written by `export_canonical`: each line
+    /// is parsed directly into a `TestItem`, with none of the category
+    /// loaders' key-guessing or field-defaulting.
+    pub async fn load_canonical(path: impl AsRef<Path>) -> Result<Vec<TestItem>> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path).await
+            .context(format!("Failed to read {:?}", path))?;
+
+        let mut items = Vec::new();
+        for (line_no, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let item: TestItem = serde_json::from_str(line)
+                .context(format!("Failed to parse {:?} line {}", path, line_no + 1))?;
+            items.push(item);
+        }
+
+        Ok(items)
+    }
+
     /// Load only specific categories
     pub async fn load_categories(&self, categories: &[TestCategory]) -> Result<Vec<TestItem>> {
         let mut items = Vec::new();
@@ -561,7 +913,8 @@ impl DatasetLoader {
         ).await
     }
 
-    /// Generic loader for new-style JSON files
+    /// Generic loader for new-style JSON (or JSONL) files. For each `file`,
+    /// `<file>.jsonl` is preferred over `<file>.json` if both exist.
     async fn load_generic_category(
         &self,
         dir_name: &str,
@@ -572,6 +925,12 @@ impl DatasetLoader {
         let mut items = Vec::new();
 
         for file in files {
+            let jsonl_path = cat_path.join(format!("{}.jsonl", file));
+            if jsonl_path.exists() {
+                items.extend(self.load_generic_jsonl(&jsonl_path, category, file).await?);
+                continue;
+            }
+
             let path = cat_path.join(format!("{}.json", file));
             if path.exists() {
                 let content = tokio::fs::read_to_string(&path).await
@@ -618,6 +977,54 @@ impl DatasetLoader {
         tracing::debug!("Loaded {} {:?} items", items.len(), category);
         Ok(items)
     }
+
+    /// Stream a `.jsonl` file (one JSON test object per line) through
+    /// `generic_to_test_item`, rather than `read_to_string`-ing the whole
+    /// file into memory the way the `.json` path does. A malformed line is
+    /// logged with its line number and skipped instead of failing the whole
+    /// file, so one corrupt record in a very large dataset doesn't take
+    /// every other record down with it.
+    async fn load_generic_jsonl(
+        &self,
+        path: &Path,
+        category: TestCategory,
+        subcategory: &str,
+    ) -> Result<Vec<TestItem>> {
+        use tokio::io::AsyncBufReadExt;
+
+        let file = tokio::fs::File::open(path).await
+            .context(format!("Failed to open {:?}", path))?;
+        let mut lines = tokio::io::BufReader::new(file).lines();
+        let mut items = Vec::new();
+        let mut line_no = 0usize;
+
+        while let Some(line) = lines.next_line().await
+            .context(format!("Failed to read {:?}", path))?
+        {
+            line_no += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let value: serde_json::Value = match serde_json::from_str(line) {
+                Ok(value) => value,
+                Err(e) => {
+                    eprintln!("[DATASET-ERR] Skipping malformed line {} in {:?}: {}", line_no, path, e);
+                    continue;
+                }
+            };
+
+            if let Some(test_item) = generic_to_test_item(&value, category, subcategory) {
+                items.push(test_item);
+            } else {
+                eprintln!("[DATASET-ERR] Failed to parse line {} in {:?}: {:?}", line_no, path, value.get("id"));
+            }
+        }
+
+        tracing::debug!("Loaded {} {:?} items from {:?}", items.len(), category, path);
+        Ok(items)
+    }
 }
 
 /// Convert generic JSON item to TestItem
@@ -626,8 +1033,21 @@ fn generic_to_test_item(
     category: TestCategory,
     subcategory: &str
 ) -> Option<TestItem> {
-    let id = item.get("id")?.as_str()?.to_string();
-    
+    generic_to_test_item_checked(item, category, subcategory).ok()
+}
+
+/// Same conversion as `generic_to_test_item`, but on failure returns a
+/// human-readable reason instead of discarding it — used by
+/// `DatasetLoader::validate` to build a `ValidationReport`.
+fn generic_to_test_item_checked(
+    item: &serde_json::Value,
+    category: TestCategory,
+    subcategory: &str
+) -> Result<TestItem, String> {
+    let id = item.get("id").ok_or("missing \"id\" field".to_string())?
+        .as_str().ok_or("\"id\" field is not a string".to_string())?
+        .to_string();
+
     // Extract prompt from various possible keys
     let prompt = item.get("prompt")
         .or_else(|| item.get("problem"))
@@ -708,7 +1128,7 @@ fn generic_to_test_item(
         }
     }
     
-    Some(TestItem {
+    Ok(TestItem {
         id,
         category,
         subcategory: subcategory.to_string(),
@@ -717,3 +1137,56 @@ fn generic_to_test_item(
         metadata,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TestCategory;
+
+    fn item(id: &str, subcategory: &str) -> TestItem {
+        TestItem {
+            id: id.to_string(),
+            category: TestCategory::CCT,
+            subcategory: subcategory.to_string(),
+            prompt: "prompt".to_string(),
+            expected: TestExpectation::ShouldExpressUncertainty,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn item_filter_matches_on_id_glob_and_subcategory() {
+        let filter = ItemFilter {
+            id_pattern: Some("hard-*".to_string()),
+            subcategories: Some(vec!["math".to_string()]),
+            metadata: Vec::new(),
+        };
+
+        assert!(filter.matches(&item("hard-001", "math")));
+        assert!(!filter.matches(&item("easy-001", "math")));
+        assert!(!filter.matches(&item("hard-001", "logic")));
+    }
+
+    #[test]
+    fn item_filter_with_no_constraints_matches_everything() {
+        let filter = ItemFilter::default();
+        assert!(filter.matches(&item("anything", "whatever")));
+    }
+
+    #[tokio::test]
+    async fn export_then_load_canonical_round_trips_items() {
+        let items = vec![item("a-1", "math"), item("a-2", "logic")];
+        let path = std::env::temp_dir().join(format!(
+            "vex-halt-dataset-test-{}.jsonl",
+            std::process::id()
+        ));
+
+        DatasetLoader::export_canonical(&items, &path).await.unwrap();
+        let reloaded = DatasetLoader::load_canonical(&path).await.unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(reloaded.len(), items.len());
+        assert_eq!(reloaded[0].id, items[0].id);
+        assert_eq!(reloaded[1].subcategory, items[1].subcategory);
+    }
+}
@@ -0,0 +1,123 @@
+//! On-demand provisioning of the benchmark dataset for packaged-crate tests
+//!
+//! `datasets/vex_halt` is not published in the crates.io tarball (and a
+//! downstream consumer may not have a copy), so resolving it unconditionally
+//! against the project root breaks outside this repository's checkout. This
+//! module fetches the dataset from the crate's source repository at the
+//! exact revision recorded in `.cargo_vcs_info.json` and unpacks it into a
+//! cache directory. The download is checked only for a successful HTTP
+//! status; the archive's contents are not checksummed or otherwise
+//! verified before extraction. Fetching happens only when the caller has
+//! explicitly opted in, since it touches the network.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Env var that must be set to a truthy value to allow fetching the dataset
+/// over the network
+pub const FETCH_OPT_IN_ENV: &str = "VEX_HALT_FETCH_DATASET";
+
+/// Contents of the `.cargo_vcs_info.json` file Cargo embeds in published
+/// tarballs, recording the exact commit the package was built from
+#[derive(Debug, serde::Deserialize)]
+struct CargoVcsInfo {
+    git: CargoVcsGit,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CargoVcsGit {
+    sha1: String,
+}
+
+/// Read the pinned revision from `.cargo_vcs_info.json` next to `Cargo.toml`,
+/// so fetched results are reproducible across published versions rather than
+/// always pulling the latest commit.
+fn pinned_revision(manifest_dir: &Path) -> Result<String> {
+    let path = manifest_dir.join(".cargo_vcs_info.json");
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {:?}; dataset cannot be fetched without a pinned revision", path))?;
+    let info: CargoVcsInfo = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {:?}", path))?;
+    Ok(info.git.sha1)
+}
+
+/// Where fetched datasets are cached, keyed by revision so different
+/// versions of the crate don't clobber each other
+fn cache_dir(revision: &str) -> PathBuf {
+    std::env::temp_dir().join("vex-halt-dataset-cache").join(revision)
+}
+
+/// Print instructions for obtaining the dataset manually, for offline or
+/// opt-out environments.
+fn print_manual_instructions(expected_path: &Path) {
+    eprintln!("VEX-HALT dataset not found at {:?}.", expected_path);
+    eprintln!("To fetch it automatically, set {}=1 and rebuild with the", FETCH_OPT_IN_ENV);
+    eprintln!("`fetch-dataset` feature enabled, e.g.:");
+    eprintln!("  {}=1 cargo test --features fetch-dataset", FETCH_OPT_IN_ENV);
+    eprintln!("Or clone the source repository and copy its `datasets/vex_halt` directory here.");
+}
+
+/// Resolve the dataset directory, fetching it on demand if it's missing and
+/// the caller has opted in via `VEX_HALT_FETCH_DATASET` (and the
+/// `fetch-dataset` feature is enabled). Returns the resolved path, or an
+/// error with manual-fetch instructions already printed to stderr.
+pub fn resolve_dataset_path(configured: &Path, manifest_dir: &Path) -> Result<PathBuf> {
+    if configured.exists() {
+        return Ok(configured.to_path_buf());
+    }
+
+    let opted_in = std::env::var(FETCH_OPT_IN_ENV)
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+
+    if !opted_in {
+        print_manual_instructions(configured);
+        bail!("dataset directory {:?} not found and fetching is not enabled", configured);
+    }
+
+    #[cfg(feature = "fetch-dataset")]
+    {
+        let revision = pinned_revision(manifest_dir)?;
+        let dest = cache_dir(&revision);
+        if dest.exists() {
+            return Ok(dest);
+        }
+        fetch_and_unpack(&revision, &dest)?;
+        Ok(dest)
+    }
+
+    #[cfg(not(feature = "fetch-dataset"))]
+    {
+        let _ = manifest_dir;
+        print_manual_instructions(configured);
+        bail!("dataset fetching requires the `fetch-dataset` feature");
+    }
+}
+
+/// Download the dataset archive for `revision` from the source repository
+/// and unpack it into `dest`. A non-success HTTP status is an error; the
+/// archive itself is extracted without a checksum check.
+#[cfg(feature = "fetch-dataset")]
+fn fetch_and_unpack(revision: &str, dest: &Path) -> Result<()> {
+    const REPO_ARCHIVE_URL_TEMPLATE: &str =
+        "https://github.com/provnai/vex-halt/archive/{revision}.tar.gz";
+
+    let url = REPO_ARCHIVE_URL_TEMPLATE.replace("{revision}", revision);
+    let response = reqwest::blocking::get(&url)
+        .with_context(|| format!("Failed to fetch dataset archive from {url}"))?;
+    if !response.status().is_success() {
+        bail!("Dataset fetch failed with status {}: {}", response.status(), url);
+    }
+    let bytes = response.bytes().context("Failed to read dataset archive body")?;
+
+    std::fs::create_dir_all(dest)
+        .with_context(|| format!("Failed to create cache directory {:?}", dest))?;
+
+    let tar = flate2::read::GzDecoder::new(&bytes[..]);
+    let mut archive = tar::Archive::new(tar);
+    archive
+        .unpack(dest)
+        .with_context(|| format!("Failed to unpack dataset archive into {:?}", dest))?;
+
+    Ok(())
+}
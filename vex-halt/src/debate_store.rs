@@ -0,0 +1,106 @@
+//! Persistent, resumable state for `vex_integration::verify_with_vex`
+//!
+//! A debate run can checkpoint after every round via a `DebateStore`,
+//! analogous to `crate::checkpoint` for whole benchmark items but at
+//! debate-round granularity: each `DebateRecord` captures one round's
+//! `DebateRound`, the running confidence, the Merkle root over rounds and
+//! equivocations so far, and the equivocation proofs detected so far. On
+//! resume, `verify_with_vex` replays a debate id's persisted records to
+//! reconstruct its response/confidence instead of re-querying the LLM, and
+//! only runs the remaining `VexDebateConfig::rounds`, checking each
+//! replayed round's Merkle root against a freshly recomputed one so a
+//! tampered or truncated log is caught rather than silently trusted.
+
+use crate::types::DebateRound;
+use crate::vex_integration::EquivocationProof;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// One persisted round of a debate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebateRecord {
+    pub round_idx: usize,
+    pub round: DebateRound,
+    pub confidence: f64,
+    pub merkle_root: String,
+    pub equivocations: Vec<EquivocationProof>,
+}
+
+/// Append-only persistence for debate rounds, keyed by debate id
+pub trait DebateStore {
+    /// Append `record` to `debate_id`'s log
+    fn append(&self, debate_id: &str, record: &DebateRecord) -> Result<()>;
+
+    /// Every record persisted for `debate_id`, in round order. An unknown
+    /// `debate_id` returns an empty history so a first run and a resumed
+    /// run can share the same call site.
+    fn load(&self, debate_id: &str) -> Result<Vec<DebateRecord>>;
+}
+
+/// `DebateStore` backed by one append-only JSONL file per debate id under
+/// `dir`
+#[derive(Debug, Clone)]
+pub struct FileDebateStore {
+    dir: PathBuf,
+}
+
+impl FileDebateStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Map `debate_id` (typically a dataset-supplied `TestItem.id`, so not
+    /// necessarily filesystem-safe) to a file under `self.dir`. Percent-encodes
+    /// every byte outside `[A-Za-z0-9_-]` (including literal `%`) so a
+    /// `debate_id` containing `/` or `..` can't escape `self.dir`, and two
+    /// distinct ids that only differ in an encoded byte can't collide onto
+    /// the same file the way a lossy "replace with `_`" scheme would.
+    fn path_for(&self, debate_id: &str) -> PathBuf {
+        let mut safe = String::with_capacity(debate_id.len());
+        for b in debate_id.bytes() {
+            let c = b as char;
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                safe.push(c);
+            } else {
+                safe.push_str(&format!("%{b:02x}"));
+            }
+        }
+        self.dir.join(format!("{safe}.jsonl"))
+    }
+}
+
+impl DebateStore for FileDebateStore {
+    fn append(&self, debate_id: &str, record: &DebateRecord) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create debate store dir {:?}", self.dir))?;
+        let path = self.path_for(debate_id);
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open debate log {:?}", path))?
+            .write_all(line.as_bytes())
+            .with_context(|| format!("Failed to append to debate log {:?}", path))
+    }
+
+    fn load(&self, debate_id: &str) -> Result<Vec<DebateRecord>> {
+        let path = self.path_for(debate_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read debate log {:?}", path))?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("Failed to parse debate log entry in {:?}", path))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,104 @@
+//! Baseline-expectations file for CI gating
+//!
+//! Following deqp-runner's model of baseline expectations plus known flakes:
+//! an optional JSON file, keyed by `TestItem.id`, records whether each item
+//! was previously observed to pass. After a run, each result is classified
+//! against its recorded expectation so callers (see `runner::execute_tests`)
+//! can tell a genuine regression from an expected failure or a pre-existing
+//! flake, and gate CI on regressions alone.
+
+use crate::types::TestCategory;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A single item's previously observed outcome
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Expectation {
+    pub passed: bool,
+}
+
+/// Map of `TestItem.id` to its recorded expectation, loaded from a JSON file
+pub type ExpectationsFile = HashMap<String, Expectation>;
+
+/// Load an expectations file. The file is a flat JSON object mapping test id
+/// to `{"passed": bool}`.
+pub fn load(path: &Path) -> Result<ExpectationsFile> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read expectations file {:?}", path))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse expectations file {:?}", path))
+}
+
+/// How an observed result compares to its recorded expectation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegressionStatus {
+    /// Passed, as expected (or no expectation was recorded)
+    Pass,
+    /// Failed, as expected (a known, still-unfixed failure)
+    ExpectedFail,
+    /// Passed where the expectation recorded a failure (the item was fixed)
+    UnexpectedPass,
+    /// Failed where the expectation recorded a pass (a regression)
+    UnexpectedFail,
+    /// Disagreed with its expectation, but reruns produced both outcomes
+    Flake,
+}
+
+/// Classify one item's outcome against its recorded expectation (if any)
+pub fn classify(passed: bool, expectation: Option<Expectation>) -> RegressionStatus {
+    match expectation {
+        None => {
+            if passed {
+                RegressionStatus::Pass
+            } else {
+                RegressionStatus::ExpectedFail
+            }
+        }
+        Some(exp) if exp.passed == passed => {
+            if passed {
+                RegressionStatus::Pass
+            } else {
+                RegressionStatus::ExpectedFail
+            }
+        }
+        Some(exp) if exp.passed && !passed => RegressionStatus::UnexpectedFail,
+        Some(_) => RegressionStatus::UnexpectedPass,
+    }
+}
+
+/// Tally of classified outcomes across a run, surfaced in `BenchmarkResults`
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegressionCounts {
+    pub pass: usize,
+    pub expected_fail: usize,
+    pub unexpected_pass: usize,
+    pub unexpected_fail: usize,
+    pub flake: usize,
+}
+
+impl RegressionCounts {
+    pub fn record(&mut self, status: RegressionStatus) {
+        match status {
+            RegressionStatus::Pass => self.pass += 1,
+            RegressionStatus::ExpectedFail => self.expected_fail += 1,
+            RegressionStatus::UnexpectedPass => self.unexpected_pass += 1,
+            RegressionStatus::UnexpectedFail => self.unexpected_fail += 1,
+            RegressionStatus::Flake => self.flake += 1,
+        }
+    }
+
+    /// True when any item regressed (CI should fail the build)
+    pub fn has_regressions(&self) -> bool {
+        self.unexpected_fail > 0
+    }
+}
+
+/// Per-category breakdown of `RegressionCounts`, so a report can show which
+/// categories introduced regressions rather than just a single global tally
+pub type ComplianceReport = HashMap<TestCategory, RegressionCounts>;
+
+/// True when any category in the report regressed
+pub fn report_has_regressions(report: &ComplianceReport) -> bool {
+    report.values().any(|counts| counts.has_regressions())
+}
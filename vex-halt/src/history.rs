@@ -0,0 +1,204 @@
+//! Historical result store with per-category change detection
+//!
+//! Each `BenchmarkResults` is serialized to its own JSON file in a results
+//! directory, named so a lexical sort orders runs chronologically
+//! (`<rfc3339-timestamp>_<provider>_<fingerprint>.json`). A later run can
+//! load a prior one (the latest, or a specific path) via `--baseline` and
+//! diff per-category scores and which test ids flipped pass/fail, gated by
+//! the same bootstrap significance test used for compare-mode improvement.
+
+use crate::config::BenchmarkConfig;
+use crate::scoring::{bootstrap_paired_difference, DEFAULT_BOOTSTRAP_RESAMPLES};
+use crate::types::{BenchmarkResults, ItemOutcome, TestCategory};
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Token a caller can pass to `load_baseline` to mean "the most recent run
+/// in the history directory" rather than a specific file path
+pub const LATEST: &str = "latest";
+
+/// Hash the parts of `BenchmarkConfig` that affect whether two runs are
+/// comparable (mode, provider, dataset, categories, VEX settings), so the
+/// stored filename doubles as a cheap compatibility check
+pub fn config_fingerprint(config: &BenchmarkConfig) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    config.mode.to_string().hash(&mut hasher);
+    config.provider.to_string().hash(&mut hasher);
+    config.dataset_path.hash(&mut hasher);
+    config.categories.hash(&mut hasher);
+    config.enable_vex.hash(&mut hasher);
+    config.debate_rounds.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Serialize `results` into `dir` (created if missing), returning the path
+/// written to
+pub fn save(results: &BenchmarkResults, dir: &Path, fingerprint: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create history directory {:?}", dir))?;
+
+    let provider = results.provider.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let filename = format!(
+        "{}_{}_{}.json",
+        results.timestamp.to_rfc3339().replace(':', "-"),
+        provider,
+        fingerprint
+    );
+    let path = dir.join(filename);
+
+    let json = serde_json::to_string_pretty(results).context("Failed to serialize BenchmarkResults")?;
+    std::fs::write(&path, json).with_context(|| format!("Failed to write history file {:?}", path))?;
+    Ok(path)
+}
+
+/// Load a specific history file
+pub fn load(path: &Path) -> Result<BenchmarkResults> {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read history file {:?}", path))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse history file {:?}", path))
+}
+
+/// Resolve `--baseline <path-or-latest>` against `dir`: `"latest"` loads the
+/// lexically-greatest (i.e. most recent, by the RFC3339-prefixed filename)
+/// `.json` file in `dir`; anything else is treated as a direct file path.
+pub fn load_baseline(selector: &str, dir: &Path) -> Result<BenchmarkResults> {
+    if selector != LATEST {
+        return load(Path::new(selector));
+    }
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read history directory {:?}", dir))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+        .collect();
+    candidates.sort();
+
+    let latest = candidates.pop().with_context(|| format!("No history files found in {:?}", dir))?;
+    load(&latest)
+}
+
+/// Whether a category's change between two runs is significant enough to
+/// call out, per `bootstrap_paired_difference`'s 95% CI gate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ChangeStatus {
+    Improved,
+    Regressed,
+    NoChange,
+}
+
+/// Per-category score delta between a baseline run and the current one
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CategoryDelta {
+    pub category: TestCategory,
+    pub baseline_score: f64,
+    pub current_score: f64,
+    pub delta: f64,
+    pub status: ChangeStatus,
+    /// Test ids that failed in the baseline run and pass now
+    pub flipped_to_pass: Vec<String>,
+    /// Test ids that passed in the baseline run and fail now
+    pub flipped_to_fail: Vec<String>,
+}
+
+/// Diff `current` against `baseline`, pairing items by `test_id` within
+/// each category and computing a significance-gated score delta plus the
+/// specific ids that flipped pass/fail, one `CategoryDelta` per category
+/// present in either run.
+pub fn diff(baseline: &BenchmarkResults, current: &BenchmarkResults) -> Vec<CategoryDelta> {
+    let mut by_category: HashMap<TestCategory, (Vec<&ItemOutcome>, Vec<&ItemOutcome>)> = HashMap::new();
+    for outcome in &baseline.item_outcomes {
+        by_category.entry(outcome.category).or_default().0.push(outcome);
+    }
+    for outcome in &current.item_outcomes {
+        by_category.entry(outcome.category).or_default().1.push(outcome);
+    }
+
+    let mut deltas: Vec<CategoryDelta> = by_category
+        .into_iter()
+        .map(|(category, (base_items, cur_items))| {
+            let base_by_id: HashMap<&str, bool> =
+                base_items.iter().map(|o| (o.test_id.as_str(), o.passed)).collect();
+            let cur_by_id: HashMap<&str, bool> =
+                cur_items.iter().map(|o| (o.test_id.as_str(), o.passed)).collect();
+
+            let baseline_score = score_of(&base_items);
+            let current_score = score_of(&cur_items);
+
+            let mut flipped_to_pass = Vec::new();
+            let mut flipped_to_fail = Vec::new();
+            let shared_ids: HashSet<&str> = base_by_id.keys().copied().collect::<HashSet<_>>()
+                .intersection(&cur_by_id.keys().copied().collect::<HashSet<_>>())
+                .copied()
+                .collect();
+            for id in shared_ids {
+                match (base_by_id[id], cur_by_id[id]) {
+                    (false, true) => flipped_to_pass.push(id.to_string()),
+                    (true, false) => flipped_to_fail.push(id.to_string()),
+                    _ => {}
+                }
+            }
+            flipped_to_pass.sort();
+            flipped_to_fail.sort();
+
+            let significance = bootstrap_paired_difference(
+                &as_pass_fail_results(&base_items),
+                &as_pass_fail_results(&cur_items),
+                DEFAULT_BOOTSTRAP_RESAMPLES,
+            );
+            let status = if significance.significant {
+                if significance.mean_difference > 0.0 {
+                    ChangeStatus::Improved
+                } else {
+                    ChangeStatus::Regressed
+                }
+            } else {
+                ChangeStatus::NoChange
+            };
+
+            CategoryDelta {
+                category,
+                baseline_score,
+                current_score,
+                delta: current_score - baseline_score,
+                status,
+                flipped_to_pass,
+                flipped_to_fail,
+            }
+        })
+        .collect();
+
+    deltas.sort_by_key(|d| format!("{:?}", d.category));
+    deltas
+}
+
+fn score_of(outcomes: &[&ItemOutcome]) -> f64 {
+    if outcomes.is_empty() {
+        return 0.0;
+    }
+    let passed = outcomes.iter().filter(|o| o.passed).count();
+    passed as f64 / outcomes.len() as f64 * 100.0
+}
+
+/// Adapt `ItemOutcome`s into the minimal `TestResult` shape
+/// `bootstrap_paired_difference` needs (test id + pass/fail)
+fn as_pass_fail_results(outcomes: &[&ItemOutcome]) -> Vec<crate::types::TestResult> {
+    outcomes
+        .iter()
+        .map(|o| crate::types::TestResult {
+            test_id: o.test_id.clone(),
+            category: o.category,
+            subcategory: String::new(),
+            passed: o.passed,
+            score: if o.passed { 100.0 } else { 0.0 },
+            confidence: None,
+            response: String::new(),
+            expected: crate::types::TestExpectation::ExactAnswer { answer: String::new() },
+            execution_time_ms: 0,
+            hash: String::new(),
+            debate_rounds: None,
+            token_usage: None,
+            diff: None,
+            metadata: HashMap::new(),
+        })
+        .collect()
+}
@@ -0,0 +1,102 @@
+//! Typed model over the VEX-HALT dataset index
+//!
+//! Complements `DatasetLoader`'s per-category `TestItem` loaders with a
+//! lighter-weight, `serde`-typed view of `index.json` and its referenced
+//! challenges, modelled after the Test262 metadata convention: load the
+//! corpus once, then filter by difficulty, category, or feature tag without
+//! re-parsing raw `serde_json::Value` trees.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Difficulty/file-naming convention used across dataset categories
+/// (`easy.json`, `medium.json`, `hard.json`, `ambiguous.json`, `unanswerable.json`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Ambiguous,
+    Unanswerable,
+}
+
+/// Expected grader behavior for a challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Expectation {
+    AnswerExpected,
+    RefusalExpected,
+    Unanswerable,
+    Ambiguous,
+}
+
+/// A single challenge/question within a category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Challenge {
+    pub id: String,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    pub expectation: Expectation,
+    /// Free-form feature tags (e.g. "adversarial", "multi_hop")
+    #[serde(default)]
+    pub tags: HashSet<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+/// A category entry in the index, with its challenges attached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Category {
+    pub name: String,
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub challenges: Vec<Challenge>,
+}
+
+/// Top-level `index.json` document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VexHaltIndex {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub statistics: serde_json::Value,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+}
+
+impl VexHaltIndex {
+    /// Load and parse an `index.json` file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read index file {:?}", path))?;
+        let index: VexHaltIndex = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse index file {:?}", path))?;
+        Ok(index)
+    }
+
+    /// Iterate every challenge across all categories
+    pub fn challenges(&self) -> impl Iterator<Item = &Challenge> {
+        self.categories.iter().flat_map(|c| c.challenges.iter())
+    }
+
+    /// Filter challenges by category name, difficulty, and/or feature tag;
+    /// any filter left `None` matches everything.
+    pub fn filter<'a>(
+        &'a self,
+        category: Option<&'a str>,
+        difficulty: Option<Difficulty>,
+        tag: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a Challenge> + 'a {
+        self.categories
+            .iter()
+            .filter(move |c| category.map(|name| c.name == name).unwrap_or(true))
+            .flat_map(|c| c.challenges.iter())
+            .filter(move |ch| difficulty.map(|d| ch.difficulty == Some(d)).unwrap_or(true))
+            .filter(move |ch| tag.map(|t| ch.tags.contains(t)).unwrap_or(true))
+    }
+}
@@ -15,22 +15,36 @@
 //! - **HHT** (20%): Hallucination Honeypot Test
 //! - **RT** (10%): Reproducibility Test
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 use colored::*;
 use std::path::PathBuf;
 
+mod asset_source;
+mod charts;
+mod checkpoint;
 mod config;
 mod dataset;
+mod dataset_fetch;
+mod debate_store;
 mod evaluator;
+mod expectations;
+mod history;
+mod index;
 mod llm_judge;
 mod merkle;
+mod planner;
+mod pricing;
 mod provider;
 mod report;
+mod rubric;
 mod runner;
 mod scoring;
+mod snapshot;
 mod tools;
 mod types;
+mod validate;
+mod vectors;
 mod vex_integration;
 
 use config::BenchmarkConfig;
@@ -46,7 +60,8 @@ struct Args {
     #[arg(short, long, default_value = "compare")]
     mode: String,
 
-    /// LLM provider: mock, mistral, deepseek, openai, claude, gemini, local
+    /// LLM provider: mock, mistral, deepseek, openai, claude, gemini, local,
+    /// bedrock, vertex_ai, replicate, openai_compatible
     #[arg(short, long, default_value = "mock")]
     provider: String,
 
@@ -54,7 +69,7 @@ struct Args {
     #[arg(short, long, default_value = "datasets/vex_halt")]
     dataset: PathBuf,
 
-    /// Output format: console, json, markdown, html
+    /// Output format: console, json, markdown, html, table
     #[arg(short, long, default_value = "console")]
     output: String,
 
@@ -81,6 +96,15 @@ struct Args {
     #[arg(long, default_value = "3")]
     debate_rounds: usize,
 
+    /// Directory for resumable, tamper-evident VEX debate-round persistence
+    /// (see crate::debate_store::FileDebateStore), keyed by item id. When
+    /// set, a dead run can pick back up mid-debate on rerun instead of
+    /// re-querying the provider from round zero. Don't reuse a debate store
+    /// directory across a different --provider/model: nothing checks that
+    /// persisted rounds were produced by the provider resuming them.
+    #[arg(long)]
+    debate_store_dir: Option<PathBuf>,
+
     /// Run in lite mode (5 items per category) for debugging
     #[arg(long)]
     lite: bool,
@@ -88,6 +112,149 @@ struct Args {
     /// Validate dataset and configuration without running API calls
     #[arg(long)]
     dry_run: bool,
+
+    /// Validate every JSON file under the dataset tree against the schema
+    /// rules and exit, reporting all violations instead of running the
+    /// benchmark
+    #[arg(long)]
+    validate_dataset: bool,
+
+    /// Path to a baseline-expectations JSON file (id -> {"passed": bool}).
+    /// When set, results are classified as pass/expected-fail/regression/flake
+    /// and the process exits non-zero if any regression is found.
+    #[arg(long)]
+    expectations: Option<PathBuf>,
+
+    /// Number of times to rerun an item that disagrees with its recorded
+    /// expectation before counting it as a regression, to detect flakes
+    #[arg(long, default_value = "0")]
+    max_flake_reruns: usize,
+
+    /// Number of items to generate/evaluate concurrently
+    #[arg(long, default_value = "5")]
+    parallelism: std::num::NonZeroUsize,
+
+    /// Seed for deterministic item-execution ordering (omit for dataset order)
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Directory to persist each run's results into, for later
+    /// run-over-run comparison via --baseline
+    #[arg(long)]
+    history_dir: Option<PathBuf>,
+
+    /// Diff this run against a prior one: a specific history JSON file
+    /// path, or "latest" for the most recent run in --history-dir
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Run only the k-th of n deterministic dataset slices, e.g. "0/4" for
+    /// the first quarter of a 4-way split (see --seed for the ordering used
+    /// before slicing)
+    #[arg(long)]
+    shard: Option<String>,
+
+    /// Path to a checkpoint file that periodically records completed
+    /// results, so a run that dies partway through can pick back up with
+    /// --resume instead of starting over
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// Skip any item whose result is already in --checkpoint and still
+    /// matches the item's current prompt
+    #[arg(long)]
+    resume: bool,
+
+    /// Ignore an existing --checkpoint file and rerun every item
+    #[arg(long)]
+    force: bool,
+
+    /// Path to a JSON file overriding the built-in per-provider token
+    /// pricing used for cost charts (see crate::pricing)
+    #[arg(long)]
+    pricing_config: Option<PathBuf>,
+
+    /// Extra Wycheproof-style grouped test-vector file to load on top of
+    /// the dataset directory (see crate::vectors). Repeatable.
+    #[arg(long)]
+    vectors: Vec<PathBuf>,
+
+    /// Keep only items whose id matches this glob (`*`) or substring
+    /// pattern (see crate::dataset::ItemFilter)
+    #[arg(long)]
+    filter_id: Option<String>,
+
+    /// Keep only items in these subcategories (comma-separated)
+    #[arg(long)]
+    filter_subcategory: Option<String>,
+
+    /// Load the dataset (honoring --dataset, --filter-id, and
+    /// --filter-subcategory), write it to this path in the canonical
+    /// one-`TestItem`-per-line JSONL format (see
+    /// crate::dataset::DatasetLoader::export_canonical), and exit without
+    /// running the benchmark
+    #[arg(long)]
+    export_dataset: Option<PathBuf>,
+
+    /// Load the dataset straight from a file previously written by
+    /// --export-dataset instead of re-parsing the --dataset directory tree
+    /// (see crate::dataset::DatasetLoader::load_canonical)
+    #[arg(long)]
+    import_dataset: Option<PathBuf>,
+
+    /// Compare LLM-judge scores against human gold labels and exit, printing
+    /// Cohen's kappa/accuracy/confusion matrix per category plus a
+    /// `{"CATEGORY": reliability}` JSON summary suitable for saving and
+    /// passing straight to --judge-reliability. Input is a JSON file of
+    /// `{"CATEGORY": {"judged": [1,2,3,...], "gold": [1,2,3,...]}, ...}`
+    /// (see crate::scoring::calibrate_judge_file).
+    #[arg(long)]
+    calibrate_judge: Option<PathBuf>,
+
+    /// Path to a JSON file of `{"CATEGORY": reliability, ...}` (reliability
+    /// in [0.0, 1.0], see --calibrate-judge) to down-weight each category's
+    /// confidence interval by how trustworthy its judge has been shown to
+    /// be. Categories absent from the file keep full trust (1.0).
+    #[arg(long)]
+    judge_reliability: Option<PathBuf>,
+
+    /// Auto-discover every dataset JSON file, summarize each category
+    /// (counts per difficulty/expectation, tag histogram), and compare
+    /// against committed `.snapshot` golden files. Set `UPDATE_EXPECT=1` to
+    /// rewrite them instead of comparing.
+    #[arg(long)]
+    check_snapshots: bool,
+
+    /// Load the whole run definition (mode, provider, rate limits, VEX
+    /// settings, category filters, ...) from a TOML or JSON file instead of
+    /// the flags above, for a reproducible, shareable benchmark definition.
+    /// API keys are still always resolved from environment variables, never
+    /// from the file. When set, every other configuration flag is ignored.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Also score subjective categories (EAS, MEM, AGT, VSM, VEX) by
+    /// consulting --provider through crate::llm_judge::run_jury, instead of
+    /// relying on rubric/pattern matching alone. Multiplies (by
+    /// --judge-jury-size) the provider calls spent on every judged item.
+    #[arg(long)]
+    enable_llm_judge: bool,
+
+    /// Number of independent judge calls to aggregate per item when
+    /// --enable-llm-judge is set (see crate::llm_judge::run_jury)
+    #[arg(long, default_value = "3")]
+    judge_jury_size: usize,
+
+    /// Qualified-majority agreement threshold for --enable-llm-judge's jury
+    /// (see crate::llm_judge::Decision::LowConfidence)
+    #[arg(long, default_value = "0.6")]
+    judge_minimum_confidence: f64,
+
+    /// Path to a JSON file of rubric/red-flag overrides for
+    /// --enable-llm-judge (see crate::llm_judge::RubricRegistry::load).
+    /// Categories absent from the file keep their built-in rubric/red-flags.
+    #[arg(long)]
+    rubric_config: Option<PathBuf>,
 }
 
 fn print_banner() {
@@ -118,30 +285,146 @@ async fn main() -> Result<()> {
         .init();
 
     let args = Args::parse();
-    
+
     print_banner();
 
-    // Parse categories
-    let categories: Option<Vec<String>> = args.categories.map(|c| {
-        c.split(',')
-            .map(|s| s.trim().to_uppercase())
-            .collect()
-    });
-
-    // Build configuration
-    let config = BenchmarkConfig {
-        mode: args.mode.parse()?,
-        provider: args.provider.parse()?,
-        dataset_path: args.dataset,
-        output_format: args.output.parse()?,
-        output_file: args.output_file,
-        num_runs: args.runs,
-        categories,
-        verbose: args.verbose,
-        enable_vex: args.enable_vex,
-        debate_rounds: args.debate_rounds,
-        lite_mode: args.lite,
-        dry_run: args.dry_run,
+    if args.validate_dataset {
+        let rules = validate::default_index_rules();
+        let errors = validate::validate_tree(&args.dataset, &rules)?;
+        if errors.is_empty() {
+            println!("{} Dataset conforms to schema ({:?})", "✓".green(), args.dataset);
+        } else {
+            println!("{} {} schema violation(s) found:", "✗".red(), errors.len());
+            for error in &errors {
+                println!("  {} {}", "•".red(), error);
+            }
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(path) = &args.export_dataset {
+        let loader = dataset::DatasetLoader::new(&args.dataset)
+            .with_context(|| format!("Failed to resolve dataset directory {:?}", args.dataset))?;
+        let filter = dataset::ItemFilter {
+            id_pattern: args.filter_id.clone(),
+            subcategories: args.filter_subcategory.clone()
+                .map(|s| s.split(',').map(|v| v.trim().to_string()).collect()),
+            metadata: Vec::new(),
+        };
+        let items = loader.load_filtered(None, &filter).await?;
+        dataset::DatasetLoader::export_canonical(&items, path).await
+            .with_context(|| format!("Failed to export dataset to {:?}", path))?;
+        println!("{} Exported {} test items to {:?}", "✓".green(), items.len(), path);
+        return Ok(());
+    }
+
+    if let Some(path) = &args.calibrate_judge {
+        let reports = scoring::calibrate_judge_file(path)?;
+        let mut reliability = std::collections::HashMap::new();
+        for (category, report) in &reports {
+            println!(
+                "{} {}: accuracy {:.1}%, kappa {:.3}, confusion {:?}",
+                "▶".green(), category, report.accuracy * 100.0, report.kappa, report.confusion.counts
+            );
+            reliability.insert(category.to_string(), scoring::judge_reliability(report));
+        }
+        println!();
+        println!("{} --judge-reliability JSON (save and pass back in):", "•".blue());
+        println!("{}", serde_json::to_string_pretty(&reliability)?);
+        return Ok(());
+    }
+
+    if args.check_snapshots {
+        let discovered = snapshot::discover_challenges(&args.dataset)?;
+        let by_category = snapshot::group_by_category(&discovered);
+        let snapshot_dir = args.dataset.join("snapshots");
+        let mut mismatches = 0usize;
+
+        for (category, challenges) in &by_category {
+            let summary = snapshot::summarize(&challenges.iter().map(|c| (*c).clone()).collect::<Vec<_>>());
+            let rendered = snapshot::render(&summary);
+            let snapshot_path = snapshot_dir.join(format!("{category}.snapshot"));
+            match snapshot::check_snapshot(&snapshot_path, &rendered) {
+                Ok(()) => println!("{} {} matches snapshot", "✓".green(), category),
+                Err(e) => {
+                    mismatches += 1;
+                    println!("{} {}: {}", "✗".red(), category, e);
+                }
+            }
+        }
+
+        if mismatches > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Build configuration: either the full run definition from --config, or
+    // the individual flags above
+    let (config, provider_config) = if let Some(config_path) = &args.config {
+        let (config, provider_config) = config::load_run_config(config_path)
+            .with_context(|| format!("Failed to load --config {:?}", config_path))?;
+        (config, Some(provider_config))
+    } else {
+        // Parse categories
+        let categories: Option<Vec<String>> = args.categories.map(|c| {
+            c.split(',')
+                .map(|s| s.trim().to_uppercase())
+                .collect()
+        });
+
+        // Parse --shard "k/n"
+        let shard: Option<(usize, usize)> = match &args.shard {
+            Some(s) => {
+                let (k, n) = s.split_once('/')
+                    .with_context(|| format!("--shard must be of the form k/n, got {:?}", s))?;
+                let shard_index: usize = k.trim().parse().context("--shard index must be a number")?;
+                let num_shards: usize = n.trim().parse().context("--shard count must be a number")?;
+                anyhow::ensure!(num_shards > 0 && shard_index < num_shards, "--shard index must be less than shard count");
+                Some((shard_index, num_shards))
+            }
+            None => None,
+        };
+
+        let config = BenchmarkConfig {
+            mode: args.mode.parse()?,
+            provider: args.provider.parse()?,
+            dataset_path: args.dataset,
+            output_format: args.output.parse()?,
+            output_file: args.output_file,
+            num_runs: args.runs,
+            categories,
+            verbose: args.verbose,
+            enable_vex: args.enable_vex,
+            debate_rounds: args.debate_rounds,
+            lite_mode: args.lite,
+            dry_run: args.dry_run,
+            expectations_path: args.expectations,
+            max_flake_reruns: args.max_flake_reruns,
+            parallelism: args.parallelism,
+            seed: args.seed,
+            history_dir: args.history_dir,
+            baseline: args.baseline,
+            shard,
+            checkpoint_path: args.checkpoint,
+            resume: args.resume,
+            force: args.force,
+            pricing_config: args.pricing_config,
+            vector_paths: args.vectors,
+            judge_reliability_path: args.judge_reliability,
+            debate_store_dir: args.debate_store_dir,
+            filter_id_pattern: args.filter_id,
+            filter_subcategories: args.filter_subcategory.map(|s| {
+                s.split(',').map(|v| v.trim().to_string()).collect()
+            }),
+            import_dataset_path: args.import_dataset,
+            enable_llm_judge: args.enable_llm_judge,
+            judge_jury_size: args.judge_jury_size,
+            judge_minimum_confidence: args.judge_minimum_confidence,
+            rubric_config_path: args.rubric_config,
+        };
+        (config, None)
     };
 
     println!("{} Configuration:", "▶".green());
@@ -161,13 +444,63 @@ async fn main() -> Result<()> {
     println!();
 
     // Create and run benchmark
-    let runner = BenchmarkRunner::new(config.clone()).await?;
+    let runner = match provider_config {
+        Some(provider_config) => BenchmarkRunner::with_provider_config(config.clone(), provider_config).await?,
+        None => BenchmarkRunner::new(config.clone()).await?,
+    };
     let results = runner.run().await?;
 
+    // Diff against a prior run, if requested
+    if let Some(selector) = &config.baseline {
+        let dir = config.history_dir.clone().unwrap_or_else(|| PathBuf::from("results"));
+        match history::load_baseline(selector, &dir) {
+            Ok(baseline_results) => {
+                println!("{} Change vs. baseline ({}):", "▶".green(), selector);
+                for delta in history::diff(&baseline_results, &results) {
+                    let (icon, label) = match delta.status {
+                        history::ChangeStatus::Improved => ("↑".green(), "improved"),
+                        history::ChangeStatus::Regressed => ("↓".red(), "regressed"),
+                        history::ChangeStatus::NoChange => ("→".dimmed(), "no change"),
+                    };
+                    println!(
+                        "  {} {:?}: {:.1} -> {:.1} ({:+.1}, {}) [{} fixed, {} broken]",
+                        icon,
+                        delta.category,
+                        delta.baseline_score,
+                        delta.current_score,
+                        delta.delta,
+                        label,
+                        delta.flipped_to_pass.len(),
+                        delta.flipped_to_fail.len()
+                    );
+                }
+                println!();
+            }
+            Err(e) => {
+                println!("{} Could not load baseline {:?}: {}", "⚠".yellow(), selector, e);
+                println!();
+            }
+        }
+    }
+
+    // Persist this run for future --baseline comparisons
+    if let Some(dir) = &config.history_dir {
+        let fingerprint = history::config_fingerprint(&config);
+        match history::save(&results, dir, &fingerprint) {
+            Ok(path) => println!("{} Saved run history to {:?}", "✓".green(), path),
+            Err(e) => println!("{} Failed to save run history: {}", "⚠".yellow(), e),
+        }
+        println!();
+    }
+
     // Generate report based on output format
+    let pricing = match &config.pricing_config {
+        Some(path) => pricing::PricingTable::load(path)?,
+        None => pricing::PricingTable::defaults(),
+    };
     match config.output_format {
         types::OutputFormat::Console => {
-            report::generate(&results)?;
+            report::generate(&results, &pricing)?;
         }
         types::OutputFormat::Json => {
             let json = report::generate_json(&results)?;
@@ -179,7 +512,7 @@ async fn main() -> Result<()> {
             }
         }
         types::OutputFormat::Markdown => {
-            let md = report::generate_markdown(&results)?;
+            let md = report::generate_markdown(&results, &pricing)?;
             if let Some(ref path) = config.output_file {
                 std::fs::write(path, &md)?;
                 println!("{} Markdown report saved to: {:?}", "✓".green(), path);
@@ -188,7 +521,7 @@ async fn main() -> Result<()> {
             }
         }
         types::OutputFormat::Html => {
-            let html = report::generate_html(&results)?;
+            let html = report::generate_html(&results, &pricing)?;
             if let Some(ref path) = config.output_file {
                 std::fs::write(path, &html)?;
                 println!("{} HTML report saved to: {:?}", "✓".green(), path);
@@ -196,9 +529,46 @@ async fn main() -> Result<()> {
                 println!("{}", html);
             }
         }
+        types::OutputFormat::Table => {
+            let table = report::render_terminal_report(&results, &pricing);
+            if let Some(ref path) = config.output_file {
+                std::fs::write(path, &table)?;
+                println!("{} Table report saved to: {:?}", "✓".green(), path);
+            } else {
+                println!("{}", table);
+            }
+        }
     }
 
     println!();
+
+    if let Some(counts) = &results.regression_counts {
+        println!(
+            "{} Expectations: {} pass, {} expected-fail, {} fixed, {} regression(s), {} flake(s)",
+            "•".blue(),
+            counts.pass,
+            counts.expected_fail,
+            counts.unexpected_pass,
+            counts.unexpected_fail,
+            counts.flake
+        );
+        if let Some(report) = &results.compliance_report {
+            for (category, cat_counts) in report {
+                if cat_counts.has_regressions() {
+                    println!(
+                        "  {} {:?}: {} regression(s)",
+                        "✗".red(), category, cat_counts.unexpected_fail
+                    );
+                }
+            }
+        }
+        if counts.has_regressions() {
+            println!("{}", "✗ Regressions found against baseline expectations!".red().bold());
+            println!();
+            std::process::exit(1);
+        }
+    }
+
     println!("{}", "✅ Benchmark complete!".green().bold());
     println!();
 
@@ -47,7 +47,11 @@ impl MerkleTree {
         self.root_hash_str.clone()
     }
 
-
+    /// Serialize the tree (root hash + leaves) into the audit-export
+    /// payload, so callers can time how long producing that payload takes
+    pub fn export_proof(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
 }
 
 impl Default for MerkleTree {
@@ -0,0 +1,88 @@
+//! Deterministic execution planning: seeded ordering and test sharding
+//!
+//! Unlike the provider-facing RNG use elsewhere in this crate (bootstrap
+//! resampling, etc.), this planner inlines its own SplitMix64 PRNG rather
+//! than pulling in `rand::SeedableRng`, so the exact sequence it produces
+//! for a given `seed` is a stable part of this crate's behavior rather than
+//! an implementation detail of whatever `rand` happens to do internally.
+//! That matters here because `seed`/`shard`/`num_shards` are persisted into
+//! `BenchmarkResults` as a promise that re-running with the same values
+//! reproduces the exact same item ordering and per-item hashes.
+//!
+//! This lives as a post-load planning step (`plan`, called from
+//! `BenchmarkRunner::run` once `DatasetLoader::load_all`/`load_categories`
+//! has concatenated every category) rather than as a `LoadOptions` the
+//! loader itself takes, so the same shuffle-then-slice logic applies no
+//! matter which loader path produced `items`. Sharding selects contiguous
+//! blocks of the shuffled order (last block absorbs the remainder), not
+//! `idx % num_shards`, so each shard's items stay contiguous in the
+//! Merkle tree built over them.
+
+use crate::types::TestItem;
+
+/// A small, fast, non-cryptographic PRNG seeded from a single `u64`. Same
+/// seed always produces the same sequence, with no runtime entropy.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound` (bound must be > 0), via Lemire's
+    /// rejection-free reduction — biased by at most `bound / 2^64`, which is
+    /// negligible for the item counts this planner shuffles.
+    fn gen_range(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+}
+
+/// Deterministically shuffle `items` in place using a Fisher-Yates shuffle
+/// driven by `SplitMix64::new(seed)`.
+fn shuffle(items: &mut [TestItem], seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(i + 1);
+        items.swap(i, j);
+    }
+}
+
+/// Keep only the `shard_index`-th of `num_shards` contiguous blocks of
+/// `items` (the last block absorbs any remainder), so shards partition the
+/// full set with no items dropped or duplicated.
+fn select_shard(items: Vec<TestItem>, shard_index: usize, num_shards: usize) -> Vec<TestItem> {
+    let len = items.len();
+    let block = len / num_shards;
+    let start = (block * shard_index).min(len);
+    let end = if shard_index + 1 == num_shards {
+        len
+    } else {
+        (block * (shard_index + 1)).min(len)
+    };
+    items.into_iter().skip(start).take(end - start).collect()
+}
+
+/// Build the reproducible execution plan for a run: seed-shuffle `items`
+/// (if `seed` is set, otherwise keep dataset order), then narrow to this
+/// worker's shard (if `shard` is set, otherwise keep everything).
+pub fn plan(mut items: Vec<TestItem>, seed: Option<u64>, shard: Option<(usize, usize)>) -> Vec<TestItem> {
+    if let Some(seed) = seed {
+        shuffle(&mut items, seed);
+    }
+
+    if let Some((shard_index, num_shards)) = shard {
+        items = select_shard(items, shard_index, num_shards);
+    }
+
+    items
+}
@@ -0,0 +1,95 @@
+//! Per-provider token pricing for cost reporting
+//!
+//! `report::generate_cost_accuracy_chart` and the HTML report used to
+//! hardcode a single "$3/M in, $15/M out" blend regardless of which provider
+//! actually ran, which silently produced wrong dollar figures for every
+//! provider whose list pricing differs from it. This table maps a provider name (see
+//! `LlmProvider::name`) to `{input_per_mtok, output_per_mtok, currency}`,
+//! with built-in defaults for every provider this crate ships, optionally
+//! overridden by a JSON config file so a deployment can track live list
+//! pricing without a rebuild.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::types::TokenUsage;
+
+/// Cost per million tokens, by token direction, for one provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricingRate {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    pub currency: String,
+}
+
+impl PricingRate {
+    /// Dollar (or other `currency`) cost of one `TokenUsage`
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        let cost_in = (usage.prompt_tokens as f64 / 1_000_000.0) * self.input_per_mtok;
+        let cost_out = (usage.completion_tokens as f64 / 1_000_000.0) * self.output_per_mtok;
+        cost_in + cost_out
+    }
+}
+
+/// Rate used for any provider with no entry in the table — the flat
+/// $3/M in, $15/M out of the constant this subsystem replaces (the same
+/// figures as the `claude` entry below). Documented rather than silently
+/// treating unknown providers as free.
+fn default_rate() -> PricingRate {
+    PricingRate { input_per_mtok: 3.0, output_per_mtok: 15.0, currency: "USD".to_string() }
+}
+
+/// Provider name (see `LlmProvider::name`) to pricing rate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricingTable {
+    rates: HashMap<String, PricingRate>,
+}
+
+impl PricingTable {
+    /// Built-in rates for every provider this crate ships, approximating
+    /// each provider's own list pricing as of when this table was written.
+    /// Meant to be close enough for cost charts out of the box; override
+    /// via `load` for exact, current figures.
+    pub fn defaults() -> Self {
+        let mut rates = HashMap::new();
+        rates.insert("mock".to_string(), PricingRate { input_per_mtok: 0.0, output_per_mtok: 0.0, currency: "USD".to_string() });
+        rates.insert("mistral".to_string(), PricingRate { input_per_mtok: 2.0, output_per_mtok: 6.0, currency: "USD".to_string() });
+        rates.insert("deepseek".to_string(), PricingRate { input_per_mtok: 0.27, output_per_mtok: 1.10, currency: "USD".to_string() });
+        rates.insert("openai".to_string(), PricingRate { input_per_mtok: 2.50, output_per_mtok: 10.0, currency: "USD".to_string() });
+        rates.insert("claude".to_string(), PricingRate { input_per_mtok: 3.0, output_per_mtok: 15.0, currency: "USD".to_string() });
+        rates.insert("gemini".to_string(), PricingRate { input_per_mtok: 0.075, output_per_mtok: 0.30, currency: "USD".to_string() });
+        rates.insert("local".to_string(), PricingRate { input_per_mtok: 0.0, output_per_mtok: 0.0, currency: "USD".to_string() });
+        rates.insert("bedrock".to_string(), PricingRate { input_per_mtok: 3.0, output_per_mtok: 15.0, currency: "USD".to_string() });
+        rates.insert("vertex_ai".to_string(), PricingRate { input_per_mtok: 0.075, output_per_mtok: 0.30, currency: "USD".to_string() });
+        rates.insert("replicate".to_string(), PricingRate { input_per_mtok: 0.65, output_per_mtok: 2.75, currency: "USD".to_string() });
+        rates.insert("openai_compatible".to_string(), default_rate());
+        Self { rates }
+    }
+
+    /// Load a pricing table from a JSON file, `{"provider_name": {"input_per_mtok": ..., "output_per_mtok": ..., "currency": ...}, ...}`.
+    /// Entries not present in the file fall back to `defaults()`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read pricing config {:?}", path))?;
+        let overrides: HashMap<String, PricingRate> = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse pricing config {:?}", path))?;
+
+        let mut table = Self::defaults();
+        table.rates.extend(overrides);
+        Ok(table)
+    }
+
+    /// The rate for `provider`, or the documented `default_rate()` when the
+    /// provider has no entry
+    pub fn rate(&self, provider: &str) -> PricingRate {
+        self.rates.get(provider).cloned().unwrap_or_else(default_rate)
+    }
+}
+
+impl Default for PricingTable {
+    fn default() -> Self {
+        Self::defaults()
+    }
+}
@@ -2,19 +2,33 @@
 
 use anyhow::Result;
 use colored::*;
-
-use crate::types::{BenchmarkResults, TestCategory};
+use tabled::settings::object::Rows;
+use tabled::settings::{Color, Modify, Style};
+use tabled::{Table, Tabled};
+
+use crate::charts::ChartKind;
+use crate::pricing::PricingTable;
+use crate::types::{BenchmarkResults, CalibrationBin, TestCategory};
+
+/// Display symbol for a pricing currency code, falling back to the code
+/// itself (space-suffixed) for anything we don't special-case
+fn currency_symbol(currency: &str) -> String {
+    match currency {
+        "USD" => "$".to_string(),
+        other => format!("{} ", other),
+    }
+}
 
 /// Generate and display the benchmark report
-pub fn generate(results: &BenchmarkResults) -> Result<()> {
+pub fn generate(results: &BenchmarkResults, pricing: &PricingTable) -> Result<()> {
     match results.mode.as_str() {
-        "compare" => generate_comparison_report(results),
-        _ => generate_single_report(results),
+        "compare" => generate_comparison_report(results, pricing),
+        _ => generate_single_report(results, pricing),
     }
 }
 
 /// Generate a comparison report (baseline vs VEX)
-fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
+fn generate_comparison_report(results: &BenchmarkResults, pricing: &PricingTable) -> Result<()> {
     let baseline = results.baseline_score.unwrap_or(0.0);
     let vex = results.vex_score.unwrap_or(0.0);
     let improvement = results.improvement.unwrap_or(0.0);
@@ -100,8 +114,16 @@ fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
         "║".cyan()
     );
 
+    if let Some(per_1k) = results.improvement_per_1k_tokens {
+        println!("{}  Score gain per 1k extra tokens: {}                          {}",
+            "║".cyan(),
+            format!("{:.2}", per_1k).bright_cyan(),
+            "║".cyan()
+        );
+    }
+
     println!("{}", "╠══════════════════════════════════════════════════════════════════╣".cyan());
-    
+
     // Performance section
     println!("{}  {}                                                  {}",
         "║".cyan(),
@@ -125,6 +147,15 @@ fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
         );
     }
 
+    if results.performance.resumed_items > 0 {
+        println!("{}  Resumed from checkpoint: {}  │  Freshly run: {}                {}",
+            "║".cyan(),
+            results.performance.resumed_items.to_string().bright_yellow(),
+            results.performance.fresh_items.to_string().bright_green(),
+            "║".cyan()
+        );
+    }
+
     println!("{}", "╠══════════════════════════════════════════════════════════════════╣".cyan());
     
     // Merkle root
@@ -158,9 +189,13 @@ fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
         .sum();
         
     let total_tokens = total_prompt + total_completion;
-    
-    // Estimate cost (Using roughly Mistral Large pricing: $2/M in, $6/M out)
-    let cost = (total_prompt as f64 / 1_000_000.0 * 2.0) + (total_completion as f64 / 1_000_000.0 * 6.0);
+
+    let rate = pricing.rate(&results.provider);
+    let cost = rate.cost(&crate::types::TokenUsage {
+        prompt_tokens: total_prompt,
+        completion_tokens: total_completion,
+        total_tokens,
+    });
 
     // Calculate Flip Rate
     let mut total_flips = 0;
@@ -265,9 +300,9 @@ fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
     }
 
     println!("{}", "╠══════════════════════════════════════════════════════════════════╣".cyan());
-    println!("{}  ESTIMATED COST: ${:.4}                                    {}",
+    println!("{}  ESTIMATED COST: {}                                    {}",
         "║".cyan(),
-        cost.to_string().bright_yellow(),
+        format!("{}{:.4}", currency_symbol(&rate.currency), cost).bright_yellow(),
         "║".cyan()
     );
     println!("{}  Tokens: {} ({} in / {} out)                {}",
@@ -322,7 +357,7 @@ fn generate_comparison_report(results: &BenchmarkResults) -> Result<()> {
 }
 
 /// Generate a single-mode report
-fn generate_single_report(results: &BenchmarkResults) -> Result<()> {
+fn generate_single_report(results: &BenchmarkResults, pricing: &PricingTable) -> Result<()> {
     println!();
     println!("{}", "═".repeat(60).cyan());
     println!("{} VEX-HALT BENCHMARK - {} MODE",
@@ -375,11 +410,16 @@ fn generate_single_report(results: &BenchmarkResults) -> Result<()> {
         .map(|t| t.completion_tokens)
         .sum();
     
-    // Estimate cost (Using roughly Mistral Large pricing: $2/M in, $6/M out)
-    let cost = (total_prompt as f64 / 1_000_000.0 * 2.0) + (total_completion as f64 / 1_000_000.0 * 6.0);
-
-    println!("  {} Est. Cost: ${:.4} ({} tokens)",
-        "$" .bright_yellow(),
+    let rate = pricing.rate(&results.provider);
+    let cost = rate.cost(&crate::types::TokenUsage {
+        prompt_tokens: total_prompt,
+        completion_tokens: total_completion,
+        total_tokens: total_prompt + total_completion,
+    });
+
+    println!("  {} Est. Cost: {}{:.4} ({} tokens)",
+        "$".bright_yellow(),
+        currency_symbol(&rate.currency),
         cost,
         (total_prompt + total_completion).to_string().dimmed()
     );
@@ -419,7 +459,7 @@ fn create_score_bar(score: f64) -> String {
 }
 
 /// Convert score to letter grade
-fn score_to_letter_grade(score: f64) -> String {
+pub(crate) fn score_to_letter_grade(score: f64) -> String {
     match score {
         s if s >= 90.0 => "A+".to_string(),
         s if s >= 80.0 => "A".to_string(),
@@ -457,8 +497,78 @@ pub fn generate_json(results: &BenchmarkResults) -> Result<String> {
     Ok(serde_json::to_string_pretty(results)?)
 }
 
+#[derive(Tabled)]
+struct CategoryTableRow {
+    #[tabled(rename = "Category")]
+    category: String,
+    #[tabled(rename = "Score")]
+    score: String,
+    #[tabled(rename = "Grade")]
+    grade: String,
+    #[tabled(rename = "Tests")]
+    tests: String,
+    #[tabled(rename = "Est. Cost")]
+    cost: String,
+}
+
+/// Render a colorized `tabled` grid — one row per category plus a summary
+/// footer row — so CI logs and headless runs get a readable table without
+/// opening the HTML report
+pub fn render_terminal_report(results: &BenchmarkResults, pricing: &PricingTable) -> String {
+    let rate = pricing.rate(&results.provider);
+
+    let mut rows = Vec::new();
+    for cat in TestCategory::all() {
+        if let Some(r) = results.categories.get(&cat) {
+            let cost: f64 = r.test_results.iter().filter_map(|t| t.token_usage.as_ref()).map(|u| rate.cost(u)).sum();
+            rows.push(CategoryTableRow {
+                category: cat.name().to_string(),
+                score: format!("{:.1}", r.score),
+                grade: score_to_letter_grade(r.score),
+                tests: r.total_tests.to_string(),
+                cost: format!("{}{:.4}", currency_symbol(&rate.currency), cost),
+            });
+        }
+    }
+
+    let total_tests: usize = results.categories.values().map(|c| c.total_tests).sum();
+    let total_cost: f64 = results
+        .categories
+        .values()
+        .flat_map(|c| c.test_results.iter())
+        .filter_map(|t| t.token_usage.as_ref())
+        .map(|u| rate.cost(u))
+        .sum();
+    rows.push(CategoryTableRow {
+        category: "TOTAL".to_string(),
+        score: format!("{:.1}", results.final_score),
+        grade: results.grade.clone(),
+        tests: total_tests.to_string(),
+        cost: format!("{}{:.4}", currency_symbol(&rate.currency), total_cost),
+    });
+
+    let mut table = Table::new(&rows);
+    table.with(Style::rounded());
+
+    // Row 0 is the header; data rows start at 1, so `idx + 1` below
+    for (idx, row) in rows.iter().enumerate() {
+        let color = match row.grade.as_str() {
+            "A+" | "A" => Color::FG_GREEN,
+            "B" => Color::FG_BLUE,
+            "C" => Color::FG_YELLOW,
+            _ => Color::FG_RED,
+        };
+        table.with(Modify::new(Rows::single(idx + 1)).with(color));
+    }
+
+    format!(
+        "{}\n\n  Throughput: {:.0} qps  │  Latency (p50): {:.0}ms",
+        table, results.performance.throughput_qps, results.performance.latency_p50_ms
+    )
+}
+
 /// Generate Markdown report
-pub fn generate_markdown(results: &BenchmarkResults) -> Result<String> {
+pub fn generate_markdown(results: &BenchmarkResults, pricing: &PricingTable) -> Result<String> {
     let mut md = String::new();
     
     md.push_str("# VEX-HALT Benchmark Results\n\n");
@@ -488,11 +598,38 @@ pub fn generate_markdown(results: &BenchmarkResults) -> Result<String> {
     if let Some(improvement) = results.improvement {
         md.push_str(&format!("**Improvement over baseline:** {:.1}%\n\n", improvement));
     }
+    if let Some(per_1k) = results.improvement_per_1k_tokens {
+        md.push_str(&format!("**Score gain per 1k extra tokens:** {:.2}\n\n", per_1k));
+    }
+
+    let total_tokens: crate::types::TokenUsage = results
+        .categories
+        .values()
+        .flat_map(|c| c.test_results.iter())
+        .filter_map(|r| r.token_usage.as_ref())
+        .fold(crate::types::TokenUsage::default(), |mut acc, t| {
+            acc.prompt_tokens += t.prompt_tokens;
+            acc.completion_tokens += t.completion_tokens;
+            acc.total_tokens += t.total_tokens;
+            acc
+        });
+    let rate = pricing.rate(&results.provider);
+    md.push_str(&format!(
+        "**Estimated Cost:** {}{:.4}\n\n",
+        currency_symbol(&rate.currency),
+        rate.cost(&total_tokens)
+    ));
 
     md.push_str("## Performance Metrics\n\n");
     md.push_str(&format!("- **Throughput:** {:.0} qps\n", results.performance.throughput_qps));
     md.push_str(&format!("- **Latency (p50):** {:.0} ms\n", results.performance.latency_p50_ms));
     md.push_str(&format!("- **Merkle Overhead:** {:.1} ms\n", results.performance.merkle_overhead_ms));
+    if results.performance.resumed_items > 0 {
+        md.push_str(&format!(
+            "- **Resumed from checkpoint:** {} (freshly run: {})\n",
+            results.performance.resumed_items, results.performance.fresh_items
+        ));
+    }
 
     md.push_str(&format!("\n**Merkle Root:** `{}`\n", results.merkle_root));
 
@@ -500,7 +637,7 @@ pub fn generate_markdown(results: &BenchmarkResults) -> Result<String> {
 }
 
 /// Generate beautiful HTML report
-pub fn generate_html(results: &BenchmarkResults) -> Result<String> {
+pub fn generate_html(results: &BenchmarkResults, pricing: &PricingTable) -> Result<String> {
     let mut categories_html = String::new();
     
     for cat in TestCategory::all() {
@@ -558,7 +695,30 @@ pub fn generate_html(results: &BenchmarkResults) -> Result<String> {
         String::new()
     };
 
-    let chart_svg = generate_cost_accuracy_chart(results);
+    let chart_svg = crate::charts::render_chart(ChartKind::CostAccuracy, results, pricing)?;
+    let category_scores_svg = crate::charts::render_chart(ChartKind::CategoryScores, results, pricing)?;
+    let latency_svg = crate::charts::render_chart(ChartKind::LatencyDistribution, results, pricing)?;
+    let latency_boxplot_svg = crate::charts::render_chart(ChartKind::LatencyBoxplot, results, pricing)?;
+
+    let reliability_section = results
+        .categories
+        .get(&TestCategory::CCT)
+        .and_then(|r| r.metrics.reliability_diagram.as_ref())
+        .filter(|bins| !bins.is_empty())
+        .map(|bins| {
+            format!(
+                r#"
+        <div class="chart-container">
+            <div class="chart-title">📐 CCT Reliability Diagram (ECE: {:.3})</div>
+            {}
+        </div>
+        "#,
+                results.categories[&TestCategory::CCT].metrics.ece.unwrap_or(0.0),
+                generate_reliability_diagram(bins)
+            )
+        })
+        .unwrap_or_default();
+
     let html = format!(r#"<!DOCTYPE html>
 <html>
 <head>
@@ -654,7 +814,23 @@ pub fn generate_html(results: &BenchmarkResults) -> Result<String> {
             <div class="chart-title">📊 Cost vs Accuracy Analysis</div>
             {}
         </div>
-        
+
+        <div class="chart-container">
+            <div class="chart-title">📶 Score by Category</div>
+            {}
+        </div>
+
+        <div class="chart-container">
+            <div class="chart-title">⏱ Latency Distribution</div>
+            {}
+        </div>
+
+        <div class="chart-container">
+            <div class="chart-title">📦 Latency by Category</div>
+            {}
+        </div>
+        {}
+
         <div class="categories">
             <h2>🏆 Results by Category</h2>
             {}
@@ -678,6 +854,10 @@ pub fn generate_html(results: &BenchmarkResults) -> Result<String> {
         results.final_score, results.grade,
         improvement_html,
         chart_svg,
+        category_scores_svg,
+        latency_svg,
+        latency_boxplot_svg,
+        reliability_section,
         categories_html,
         results.performance.throughput_qps,
         results.performance.latency_p50_ms,
@@ -689,84 +869,58 @@ pub fn generate_html(results: &BenchmarkResults) -> Result<String> {
     Ok(html)
 }
 
-/// Generate SVG scatter plot for Cost vs Accuracy
-fn generate_cost_accuracy_chart(results: &BenchmarkResults) -> String {
-    let width = 800;
-    let height = 400;
-    let padding = 60;
-    
-    // Calculate data points
-    let mut points: Vec<(String, f64, f64, String)> = Vec::new(); // (Label, Score, Cost, Color)
-    let mut max_cost = 0.0;
-    
-    for (cat, result) in &results.categories {
-        let score = result.score;
-        
-        // Calculate total cost for category
-        let mut total_cost = 0.0;
-        for test in &result.test_results {
-            if let Some(usage) = &test.token_usage {
-                // Approximate cost: $3/M in, $15/M out (Mistral Large / GPT-4o blend)
-                let cost_in = (usage.prompt_tokens as f64 / 1_000_000.0) * 3.0;
-                let cost_out = (usage.completion_tokens as f64 / 1_000_000.0) * 15.0;
-                total_cost += cost_in + cost_out;
-            }
-        }
-        
-        if total_cost > max_cost { max_cost = total_cost; }
-        
-        let color = match score_to_letter_grade(score).as_str() {
-            "A+" | "A" => "#3fb950",
-            "B" => "#58a6ff",
-            "C" => "#d29922",
-            _ => "#f85149",
-        };
-        
-        points.push((cat.name().to_string(), score, total_cost, color.to_string()));
-    }
-    
-    // Avoid division by zero
-    if max_cost == 0.0 { max_cost = 1.0; }
-    
+
+/// Generate an SVG reliability diagram: observed accuracy vs. mean predicted
+/// confidence for each calibration bin, against the perfect-calibration
+/// diagonal. Point radius scales with bin sample count so sparsely
+/// populated bins don't visually dominate well-populated ones.
+fn generate_reliability_diagram(bins: &[CalibrationBin]) -> String {
+    let width = 420;
+    let height = 420;
+    let padding = 50;
+    let plot_size = (width - 2 * padding) as f64;
+    let max_count = bins.iter().map(|b| b.count).max().unwrap_or(1).max(1) as f64;
+
     let mut svg = String::new();
-    svg.push_str(&format!(r##"<svg viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg" style="background:transparent; max-width:100%;">"##, width, height));
-    
+    svg.push_str(&format!(
+        r##"<svg viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg" style="background:transparent; max-width:100%;">"##,
+        width, height
+    ));
+
     // Axes
-    svg.push_str(&format!(r##"
+    svg.push_str(&format!(
+        r##"
         <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#30363d" stroke-width="2"/>
         <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#30363d" stroke-width="2"/>
-        <text x="{}" y="{}" fill="#8b949e" text-anchor="middle" font-size="12">Cost ($)</text>
-        <text x="{}" y="{}" fill="#8b949e" text-anchor="middle" font-size="12" transform="rotate(-90, {}, {})">Score (%)</text>
-    "##, 
-        padding, height - padding, width - padding, height - padding, // X-axis
-        padding, height - padding, padding, padding, // Y-axis
+        <text x="{}" y="{}" fill="#8b949e" text-anchor="middle" font-size="12">Confidence</text>
+        <text x="{}" y="{}" fill="#8b949e" text-anchor="middle" font-size="12" transform="rotate(-90, {}, {})">Accuracy</text>
+    "##,
+        padding, height - padding, width - padding, height - padding,
+        padding, height - padding, padding, padding,
         width / 2, height - 10,
-        20, height / 2, 20, height / 2
+        16, height / 2, 16, height / 2
     ));
-    
-    // Grid lines (horizontal)
-    for i in 0..=5 {
-        let y = height - padding - (i * (height - 2 * padding) / 5);
-        svg.push_str(&format!(r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#21262d" stroke-width="1"/>"##, 
-            padding, y, width - padding, y));
-        svg.push_str(&format!(r##"<text x="{}" y="{}" fill="#6e7681" text-anchor="end" font-size="10" alignment-baseline="middle">{}%</text>"##, 
-            padding - 10, y, i * 20));
-    }
 
-    // Plot points
-    for (label, score, cost, color) in points {
-        let x = padding as f64 + (cost / max_cost) * (width - 2 * padding) as f64;
-        let y = (height - padding) as f64 - (score / 100.0) * (height - 2 * padding) as f64;
-        
-        // Point
-        svg.push_str(&format!(r##"
-            <circle cx="{:.1}" cy="{:.1}" r="6" fill="{}" stroke="#0d1117" stroke-width="2">
-                <title>{}: {:.1}% / ${:.5}</title>
+    // Perfect-calibration diagonal
+    svg.push_str(&format!(
+        r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#30363d" stroke-width="1" stroke-dasharray="4,4"/>"##,
+        padding, height - padding, width - padding, padding
+    ));
+
+    for bin in bins {
+        let x = padding as f64 + bin.confidence * plot_size;
+        let y = (height - padding) as f64 - bin.accuracy * plot_size;
+        let radius = 4.0 + 8.0 * (bin.count as f64 / max_count).sqrt();
+        svg.push_str(&format!(
+            r##"
+            <circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="#58a6ff" fill-opacity="0.7" stroke="#0d1117" stroke-width="1">
+                <title>conf {:.2}, acc {:.2}, n={}</title>
             </circle>
-            <text x="{:.1}" y="{:.1}" fill="#c9d1d9" font-size="11" font-weight="bold" text-anchor="middle" dy="-10">{}</text>
-        "##, x, y, color, label, score, cost, x, y, label));
+        "##,
+            x, y, radius, bin.confidence, bin.accuracy, bin.count
+        ));
     }
-    
+
     svg.push_str("</svg>");
     svg
 }
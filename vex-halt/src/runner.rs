@@ -2,19 +2,23 @@
 
 #![allow(dead_code)]  // simulate_vex_debate kept for fallback/testing
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::Utc;
 use colored::*;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::collections::HashMap;
 use std::time::{Instant, Duration};
 
 use crate::config::BenchmarkConfig;
 use crate::dataset::DatasetLoader;
 use crate::evaluator::evaluate_test;
+use crate::expectations::{self, Expectation, ExpectationsFile, RegressionCounts, RegressionStatus};
 use crate::merkle::MerkleTree;
-use crate::provider::{create_provider, LlmProvider};
-use crate::scoring::{build_category_result, calculate_final_score};
+use crate::provider::{create_provider, create_provider_with_config, LlmProvider};
+use crate::scoring::{
+    bootstrap_final_score_ci, bootstrap_paired_difference, build_category_result_weighted,
+    calculate_final_score, load_reliability_table, DEFAULT_BOOTSTRAP_RESAMPLES,
+};
 use crate::types::*;
 use futures::stream::StreamExt;
 
@@ -23,13 +27,37 @@ pub struct BenchmarkRunner {
     config: BenchmarkConfig,
     provider: Box<dyn LlmProvider>,
     dataset: DatasetLoader,
+    expectations: Option<ExpectationsFile>,
+    /// Per-category judge-trust weights from `--judge-reliability` (see
+    /// `crate::scoring::load_reliability_table`). Categories absent here keep
+    /// the full-trust default of `1.0` in `aggregate_by_category`.
+    judge_reliability: HashMap<TestCategory, f64>,
+    /// Rubric/red-flag overrides for `--enable-llm-judge` (see
+    /// `crate::llm_judge::RubricRegistry`). Built-in defaults unless
+    /// `config.rubric_config_path` was set.
+    rubric_registry: crate::llm_judge::RubricRegistry,
 }
 
 impl BenchmarkRunner {
-    /// Create a new benchmark runner
+    /// Create a new benchmark runner, using `config.provider`'s named
+    /// default provider config (API keys resolved from environment
+    /// variables)
     pub async fn new(config: BenchmarkConfig) -> Result<Self> {
         let provider = create_provider(config.provider);
-        let dataset = DatasetLoader::new(&config.dataset_path);
+        Self::with_provider(config, provider).await
+    }
+
+    /// Create a new benchmark runner against an explicit `ProviderConfig`
+    /// instead of `config.provider`'s named default — e.g. one resolved from
+    /// a file via `config::load_run_config`
+    pub async fn with_provider_config(config: BenchmarkConfig, provider_config: crate::config::ProviderConfig) -> Result<Self> {
+        let provider = create_provider_with_config(config.provider, provider_config);
+        Self::with_provider(config, provider).await
+    }
+
+    async fn with_provider(config: BenchmarkConfig, provider: Box<dyn LlmProvider>) -> Result<Self> {
+        let dataset = DatasetLoader::new(&config.dataset_path)
+            .with_context(|| format!("Failed to resolve dataset directory {:?}", config.dataset_path))?;
 
         if !provider.is_available() {
             tracing::warn!(
@@ -38,22 +66,103 @@ impl BenchmarkRunner {
             );
         }
 
+        let expectations = match &config.expectations_path {
+            Some(path) => Some(expectations::load(path)?),
+            None => None,
+        };
+
+        let judge_reliability = match &config.judge_reliability_path {
+            Some(path) => load_reliability_table(path)?,
+            None => HashMap::new(),
+        };
+
+        let rubric_registry = match &config.rubric_config_path {
+            Some(path) => crate::llm_judge::RubricRegistry::load(path)?,
+            None => crate::llm_judge::RubricRegistry::new(),
+        };
+
         Ok(Self {
             config,
             provider,
             dataset,
+            expectations,
+            judge_reliability,
+            rubric_registry,
         })
     }
 
+    /// Check that `self.provider.model()` actually exists on the provider
+    /// before a dry run declares the configuration valid, catching a
+    /// deprecated or misspelled model name before any real tokens are spent.
+    /// Providers that don't support `list_models` (Bedrock, Vertex AI,
+    /// Replicate, Mock) skip the check silently rather than failing the dry
+    /// run over a capability gap, but any other error (bad credentials, a
+    /// network failure) is a real problem and must still fail the dry run.
+    async fn validate_model(&self) -> Result<()> {
+        let requested = self.provider.model();
+        let models = match self.provider.list_models().await {
+            Ok(models) => models,
+            Err(e) if e.to_string().contains(crate::provider::MODEL_LISTING_UNSUPPORTED) => return Ok(()),
+            Err(e) => return Err(e).context("Failed to validate configured model"),
+        };
+
+        if models.iter().any(|m| m == requested) {
+            return Ok(());
+        }
+
+        let mut suggestions = models;
+        suggestions.sort();
+        anyhow::bail!(
+            "Model {:?} was not found on provider {:?}. Available models: {}",
+            requested,
+            self.provider.name(),
+            suggestions.join(", ")
+        );
+    }
+
     /// Run the benchmark
     pub async fn run(&self) -> Result<BenchmarkResults> {
         let start_time = Instant::now();
         
-        // Load dataset
+        // Load dataset: straight from a canonical --import-dataset file if
+        // one was given, otherwise the --dataset directory tree, keeping
+        // only what --filter-id/--filter-subcategory ask for, if anything
+        // (an unset ItemFilter matches every item)
         println!("{} Loading dataset...", "▶".yellow());
-        let items = self.dataset.load_all().await?;
+        let mut items = match &self.config.import_dataset_path {
+            Some(path) => crate::dataset::DatasetLoader::load_canonical(path).await
+                .with_context(|| format!("Failed to load --import-dataset file {:?}", path))?,
+            None => {
+                let filter = crate::dataset::ItemFilter {
+                    id_pattern: self.config.filter_id_pattern.clone(),
+                    subcategories: self.config.filter_subcategories.clone(),
+                    metadata: Vec::new(),
+                };
+                self.dataset.load_filtered(None, &filter).await?
+            }
+        };
         println!("  {} Loaded {} test items", "✓".green(), items.len());
 
+        // Layer on any hand-authored Wycheproof-style test-vector files
+        for path in &self.config.vector_paths {
+            let vector_items = crate::vectors::load(path)
+                .with_context(|| format!("Failed to load --vectors file {:?}", path))?;
+            println!("  {} Loaded {} test items from {:?}", "✓".green(), vector_items.len(), path);
+            items.extend(vector_items);
+        }
+
+        // Results, checkpointing, and baseline diffing all key purely by id,
+        // so a --vectors file that reuses an existing id would silently
+        // collide with it downstream; warn up front instead.
+        if !self.config.vector_paths.is_empty() {
+            let mut seen = std::collections::HashSet::new();
+            for item in &items {
+                if !seen.insert(item.id.as_str()) {
+                    println!("  {} Duplicate item id {:?} (dataset and/or --vectors files) — one will be shadowed downstream", "⚠".yellow(), item.id);
+                }
+            }
+        }
+
         // Filter by categories if specified
         let items = if let Some(ref cats) = self.config.categories {
             let cat_set: std::collections::HashSet<_> = cats.iter()
@@ -87,10 +196,19 @@ impl BenchmarkRunner {
             items
         };
 
+        // Seed-order (and optionally shard) the items so distributed/rerun
+        // executions produce identical, verifiable subsets.
+        let items = crate::planner::plan(items, self.config.seed, self.config.shard);
+        if let Some((shard_index, num_shards)) = self.config.shard {
+            println!("  {} Running shard {}/{}", "ℹ".blue(), shard_index, num_shards);
+        }
+
         println!("  {} Running {} test items", "✓".green(), items.len());
         println!();
 
         if self.config.dry_run {
+            self.validate_model().await?;
+
             println!("{} Dry run complete!", "▶".green());
             println!("  {} Loaded and verified {} test items across categories.", "✓".green(), items.len());
             println!("  {} Configuration is valid.", "✓".green());
@@ -104,12 +222,21 @@ impl BenchmarkRunner {
                 categories: HashMap::new(),
                 final_score: 0.0,
                 grade: "N/A".to_string(),
-                performance: self.calculate_performance_metrics(0, &[], Duration::from_secs(0)),
+                performance: self.calculate_performance_metrics(0, &[], Duration::from_secs(0), 0.0, 0.0, 0, 0),
                 merkle_root: "N/A".to_string(),
                 baseline_score: None,
                 vex_score: None,
                 improvement: None,
+                improvement_per_1k_tokens: None,
                 baseline_categories: None,
+                score_confidence_interval: None,
+                improvement_significance: None,
+                regression_counts: None,
+                compliance_report: None,
+                item_outcomes: Vec::new(),
+                seed: self.config.seed,
+                shard: self.config.shard.map(|(i, _)| i),
+                num_shards: self.config.shard.map(|(_, n)| n),
             });
         }
 
@@ -131,15 +258,18 @@ impl BenchmarkRunner {
         println!("{} Running baseline benchmark...", "▶".yellow());
         
         let total_start = Instant::now();
-        let test_results = self.execute_tests(items, false).await?;
+        let (test_results, resumed_items, fresh_items) = self.execute_tests(items, false, None, "Baseline").await?;
         let category_results = self.aggregate_by_category(test_results.clone());
         let final_score = calculate_final_score(&category_results);
 
         // Build Merkle tree from all results for cryptographic verification
-        let merkle_items: Vec<&str> = test_results.iter()
-            .map(|r| r.hash.as_str())
-            .collect();
-        let merkle_tree = MerkleTree::from_items(&merkle_items);
+        let (merkle_tree, merkle_overhead_ms, audit_export_time_ms) = Self::build_merkle_tree_timed(&test_results);
+        let score_confidence_interval =
+            Some(bootstrap_final_score_ci(&test_results, DEFAULT_BOOTSTRAP_RESAMPLES));
+        let (regression_counts, compliance_report) = match self.classify_against_expectations(&test_results, items, false).await {
+            Some((counts, report)) => (Some(counts), Some(report)),
+            None => (None, None),
+        };
 
         Ok(BenchmarkResults {
             timestamp: Utc::now(),
@@ -149,12 +279,29 @@ impl BenchmarkRunner {
             categories: category_results,
             final_score,
             grade: BenchmarkResults::score_to_grade(final_score),
-            performance: self.calculate_performance_metrics(items.len(), &test_results, total_start.elapsed()),
+            performance: self.calculate_performance_metrics(
+                items.len(),
+                &test_results,
+                total_start.elapsed(),
+                merkle_overhead_ms,
+                audit_export_time_ms,
+                resumed_items,
+                fresh_items,
+            ),
             merkle_root: merkle_tree.root_hash(),
             baseline_score: Some(final_score),
             vex_score: None,
             improvement: None,
+            improvement_per_1k_tokens: None,
             baseline_categories: None,
+            score_confidence_interval,
+            improvement_significance: None,
+            regression_counts,
+            compliance_report,
+            item_outcomes: self.item_outcomes(&test_results),
+            seed: self.plan_fields().0,
+            shard: self.plan_fields().1,
+            num_shards: self.plan_fields().2,
         })
     }
 
@@ -163,16 +310,19 @@ impl BenchmarkRunner {
         println!("{} Running VEX mode (adversarial verification)...", "▶".yellow());
         
         let total_start = Instant::now();
-        let test_results = self.execute_tests(items, true).await?;
+        let (test_results, resumed_items, fresh_items) = self.execute_tests(items, true, None, "VEX").await?;
         let total_duration = total_start.elapsed();
         let category_results = self.aggregate_by_category(test_results.clone());
         let final_score = calculate_final_score(&category_results);
 
         // Build Merkle tree from all results
-        let merkle_items: Vec<&str> = test_results.iter()
-            .map(|r| r.hash.as_str())
-            .collect();
-        let merkle_tree = MerkleTree::from_items(&merkle_items);
+        let (merkle_tree, merkle_overhead_ms, audit_export_time_ms) = Self::build_merkle_tree_timed(&test_results);
+        let score_confidence_interval =
+            Some(bootstrap_final_score_ci(&test_results, DEFAULT_BOOTSTRAP_RESAMPLES));
+        let (regression_counts, compliance_report) = match self.classify_against_expectations(&test_results, items, true).await {
+            Some((counts, report)) => (Some(counts), Some(report)),
+            None => (None, None),
+        };
 
         Ok(BenchmarkResults {
             timestamp: Utc::now(),
@@ -182,12 +332,29 @@ impl BenchmarkRunner {
             categories: category_results,
             final_score,
             grade: BenchmarkResults::score_to_grade(final_score),
-            performance: self.calculate_performance_metrics(items.len(), &test_results, total_duration),
+            performance: self.calculate_performance_metrics(
+                items.len(),
+                &test_results,
+                total_duration,
+                merkle_overhead_ms,
+                audit_export_time_ms,
+                resumed_items,
+                fresh_items,
+            ),
             merkle_root: merkle_tree.root_hash(),
             baseline_score: None,
             vex_score: Some(final_score),
             improvement: None,
+            improvement_per_1k_tokens: None,
             baseline_categories: None,
+            score_confidence_interval,
+            improvement_significance: None,
+            regression_counts,
+            compliance_report,
+            item_outcomes: self.item_outcomes(&test_results),
+            seed: self.plan_fields().0,
+            shard: self.plan_fields().1,
+            num_shards: self.plan_fields().2,
         })
     }
 
@@ -198,37 +365,53 @@ impl BenchmarkRunner {
 
         let total_start = Instant::now();
 
-        // Run baseline
+        // Run baseline and VEX concurrently, sharing one MultiProgress so
+        // both phases render as labeled bars side by side instead of one
+        // bar after the other.
         println!("{}", "━".repeat(60).dimmed());
-        println!("{} Phase 1: Baseline (raw LLM)", "▶".cyan());
+        println!("{} Running baseline and VEX phases concurrently", "▶".cyan());
         println!("{}", "━".repeat(60).dimmed());
-        let baseline_results = self.execute_tests(items, false).await?;
-        let baseline_categories = self.aggregate_by_category(baseline_results);
+        let mp = MultiProgress::new();
+        let (
+            (baseline_results, baseline_resumed, baseline_fresh),
+            (vex_results, vex_resumed, vex_fresh),
+        ) = tokio::try_join!(
+            self.execute_tests(items, false, Some(&mp), "Baseline"),
+            self.execute_tests(items, true, Some(&mp), "VEX"),
+        )?;
+
+        let baseline_categories = self.aggregate_by_category(baseline_results.clone());
         let baseline_score = calculate_final_score(&baseline_categories);
-        
-        println!();
-        println!("  {} Baseline score: {:.1}", "→".yellow(), baseline_score);
-        println!();
-
-        // Run VEX
-        println!("{}", "━".repeat(60).dimmed());
-        println!("{} Phase 2: VEX (adversarial verification)", "▶".cyan());
-        println!("{}", "━".repeat(60).dimmed());
-        let vex_results = self.execute_tests(items, true).await?;
         let vex_categories = self.aggregate_by_category(vex_results.clone());
         let vex_score = calculate_final_score(&vex_categories);
 
         println!();
+        println!("  {} Baseline score: {:.1}", "→".yellow(), baseline_score);
         println!("  {} VEX score: {:.1}", "→".yellow(), vex_score);
         println!();
 
         // Build Merkle tree
-        let merkle_items: Vec<&str> = vex_results.iter()
-            .map(|r| r.hash.as_str())
-            .collect();
-        let merkle_tree = MerkleTree::from_items(&merkle_items);
+        let (merkle_tree, merkle_overhead_ms, audit_export_time_ms) = Self::build_merkle_tree_timed(&vex_results);
 
         let improvement = vex_score - baseline_score;
+        let improvement_per_1k_tokens = crate::scoring::improvement_per_1k_tokens(
+            baseline_score,
+            vex_score,
+            total_tokens(&baseline_results),
+            total_tokens(&vex_results),
+        );
+
+        let score_confidence_interval =
+            Some(bootstrap_final_score_ci(&vex_results, DEFAULT_BOOTSTRAP_RESAMPLES));
+        let improvement_significance = Some(bootstrap_paired_difference(
+            &baseline_results,
+            &vex_results,
+            DEFAULT_BOOTSTRAP_RESAMPLES,
+        ));
+        let (regression_counts, compliance_report) = match self.classify_against_expectations(&vex_results, items, true).await {
+            Some((counts, report)) => (Some(counts), Some(report)),
+            None => (None, None),
+        };
 
         Ok(BenchmarkResults {
             timestamp: Utc::now(),
@@ -238,151 +421,128 @@ impl BenchmarkRunner {
             categories: vex_categories,
             final_score: vex_score,
             grade: BenchmarkResults::score_to_grade(vex_score),
-            performance: self.calculate_performance_metrics(items.len(), &vex_results, total_start.elapsed()),
+            performance: self.calculate_performance_metrics(
+                items.len(),
+                &vex_results,
+                total_start.elapsed(),
+                merkle_overhead_ms,
+                audit_export_time_ms,
+                baseline_resumed + vex_resumed,
+                baseline_fresh + vex_fresh,
+            ),
             merkle_root: merkle_tree.root_hash(),
             baseline_score: Some(baseline_score),
             vex_score: Some(vex_score),
             improvement: Some(improvement),
+            improvement_per_1k_tokens,
             baseline_categories: Some(baseline_categories), // Store per-category baselines!
+            score_confidence_interval,
+            improvement_significance,
+            regression_counts,
+            compliance_report,
+            item_outcomes: self.item_outcomes(&vex_results),
+            seed: self.plan_fields().0,
+            shard: self.plan_fields().1,
+            num_shards: self.plan_fields().2,
         })
     }
 
-    /// Execute tests with optional VEX verification
-    async fn execute_tests(&self, items: &[TestItem], with_vex: bool) -> Result<Vec<TestResult>> {
-        
-        let pb = ProgressBar::new(items.len() as u64);
+    /// Execute tests with optional VEX verification. `mp`, when given,
+    /// attaches this run's progress bar to a shared `MultiProgress` (used by
+    /// `run_compare` so the baseline and VEX bars render side by side while
+    /// both phases run concurrently) instead of drawing a standalone bar.
+    /// Returns `(results, resumed_count, fresh_count)`, where `resumed_count`
+    /// is the number of items whose result came from the checkpoint file
+    /// (see `crate::checkpoint`) rather than being run against the provider.
+    async fn execute_tests(
+        &self,
+        items: &[TestItem],
+        with_vex: bool,
+        mp: Option<&MultiProgress>,
+        label: &str,
+    ) -> Result<(Vec<TestResult>, usize, usize)> {
+
+        let checkpoint = match &self.config.checkpoint_path {
+            Some(path) if self.config.resume && !self.config.force => {
+                crate::checkpoint::Checkpoint::load(path)?
+            }
+            _ => crate::checkpoint::Checkpoint::default(),
+        };
+
+        let (cached, to_run): (Vec<&TestItem>, Vec<&TestItem>) =
+            items.iter().partition(|item| checkpoint.get(item).is_some());
+        let resumed_count = cached.len();
+        let mut results: Vec<TestResult> = cached
+            .into_iter()
+            .filter_map(|item| checkpoint.get(item).cloned())
+            .collect();
+
+        let pb = ProgressBar::new(to_run.len() as u64);
         pb.set_style(
             ProgressStyle::default_bar()
-                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+                .template("{prefix:>10.bold} {spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
                 .unwrap()
                 .progress_chars("#>-")
         );
+        pb.set_prefix(label.to_string());
+        let pb = match mp {
+            Some(mp) => mp.add(pb),
+            None => pb,
+        };
 
+        // Process items concurrently to speed up API calls, capped at the
+        // configured parallelism (Mistral free tier is 1 RPS, paid tiers
+        // allow more; other providers differ, so this is tunable per run).
+        let concurrency = self.config.parallelism.get();
 
+        // Execution order was already fixed by `crate::planner::plan` in
+        // `run()` (seed-shuffled and sharded), so stream items as given.
+        let ordered: Vec<&TestItem> = to_run;
 
-        // Process items in parallel to speed up API calls
-        // Mistral API limits: Free 1 RPS, Paid ~5-10 concurrent. We use 5 to be safe.
-        let concurrency = 5; 
-        
         // Wrap shared resources in Arc for parallel access
         // self.provider is a Box<dyn LlmProvider> which might not be Clone, so we wrap the reference or the box
         // But to share across threads we need Send+Sync which the trait has.
         // Best approach: create a shared reference via Arc
         // Actually, earlier I had `let provider = Arc::new(&self.provider)` which works as Arc<Box<dyn...>>
-        
+
         let provider_arc = std::sync::Arc::new(&self.provider);
         let config_arc = std::sync::Arc::new(&self.config);
-        
-        let results = futures::stream::iter(items)
+        let rubric_registry_arc = std::sync::Arc::new(&self.rubric_registry);
+        let checkpoint = std::sync::Arc::new(std::sync::Mutex::new(checkpoint));
+        let checkpoint_path = self.config.checkpoint_path.clone().map(std::sync::Arc::new);
+        // Stops scheduling new items once a provider trips
+        // `provider::CIRCUIT_BREAKER_TRIPPED`, so a dead endpoint aborts the
+        // run with whatever partial results already completed instead of
+        // retrying through the rest of the dataset.
+        let circuit_broken = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let fresh_results = futures::stream::iter(ordered)
             .map(|item| {
                 // Clone needed references for the future
                 let pb = pb.clone();
                 let provider = provider_arc.clone();
                 let config = config_arc.clone();
-                
+                let rubric_registry = rubric_registry_arc.clone();
+                let checkpoint = checkpoint.clone();
+                let checkpoint_path = checkpoint_path.clone();
+                let circuit_broken = circuit_broken.clone();
+
                 async move {
-                    let start = Instant::now();
-                    
-                    // Build system prompt
-                    let system_prompt = if with_vex {
-                        Some(VEX_SYSTEM_PROMPT)
-                    } else {
-                        Some(BASELINE_SYSTEM_PROMPT)
-                    };
-
-                    // Enhance prompt for MTC category to ensure JSON output
-                    let final_system_prompt = if item.category == TestCategory::MTC {
-                        let base = system_prompt.unwrap_or("");
-                        Some(format!("{}\n\nCRITICAL INSTRUCTION: You must answer ONLY with a JSON array of tool steps. Do not explain. Format: [{{\"tool\": \"tool_name\", \"params\": {{...}}, \"output_key\": \"result\"}}]", base))
-                    } else {
-                        system_prompt.map(|s| s.to_string())
-                    };
-
-                    // Implement retry logic for rate limits (simple backoff handled in provider, here we handle per-item)
-                    let response_result = provider.generate(&item.prompt, final_system_prompt.as_deref()).await;
-                    
-                    let response = match response_result {
-                        Ok(r) => r,
-                        Err(e) => {
-                            tracing::error!("Generation failed for item {}: {}", item.id, e);
-                            eprintln!("\n[ERROR] Generation failed for {}: {}", item.id, e);
-                            pb.inc(1);
-                            return None; // Skip failed items for now
-                        }
-                    };
-                    
-                    let execution_time = start.elapsed().as_millis() as u64;
-                    
-                    // Evaluate
-                    let (debate_rounds, response_to_eval, semantic_entropy) = if with_vex {
-                        // LAZY VEX CHECK: Only run if prompt is suspicious or category demands it
-                        // For FRONTIER, AGT, VEX categories, always run.
-                        let always_verify = matches!(item.category,
-                            TestCategory::FRONTIER | TestCategory::AGT | TestCategory::VEX | TestCategory::API | TestCategory::HHT
-                        );
-                        
-                        let should_run_vex = always_verify || crate::vex_integration::is_suspicious_prompt(&item.prompt);
-                        
-                        // We can verify "skipped" behavior by checking logs
-                        if !should_run_vex {
-                             // println!("[DEBUG] Lazy VEX: Skipping verification for safe prompt: {}", item.id);
-                             // Return as if VEX wasn't run
-                             (None, response.clone(), None)
-                        } else {
-                            // Use real VEX verification
-                            use crate::vex_integration::{verify_with_vex, VexDebateConfig};
-                        
-                            // Use less aggressive verification for semantic/calibration tasks
-                            let aggressive_mode = !matches!(item.category,
-                                TestCategory::CCT | TestCategory::VSM | TestCategory::EAS
-                            );
-                            
-                            let vex_config = VexDebateConfig {
-                                rounds: config.debate_rounds,
-                                confidence_threshold: 0.7,
-                                aggressive_mode,
-                                early_stopping: true,
-                            };
-                            
-                            // VEX verification also calls the provider, handled by same concurrency limit
-                            match verify_with_vex((*provider).as_ref(), &item.prompt, &response.content, &vex_config).await {
-                                Ok(vex_result) => {
-                                    let mut new_response = response.clone();
-                                    new_response.content = vex_result.final_response;
-                                    new_response.confidence = Some(vex_result.confidence);
-                                    // VEX likely used more tokens, but for now we keep base response tokens or sum them if VEX returned usage
-                                    // Note: In a full impl, vex_result would carry its own token usage
-                                    
-                                    (Some(vex_result.rounds), new_response, Some(vex_result.semantic_entropy))
-                                },
-                                Err(e) => {
-                                    tracing::error!("VEX verification failed: {}", e);
-                                    eprintln!("  [WARN] VEX verification error: {}", e);
-                                    (None, response.clone(), None)
-                                }
+                    let result = run_one_item((*provider).as_ref(), &config, *rubric_registry, item, with_vex, &circuit_broken).await;
+                    if let Some(result) = &result {
+                        // Checkpoint as each item completes, not just at the
+                        // end, so a mid-run crash loses as little progress
+                        // as possible.
+                        let mut cp = checkpoint.lock().unwrap();
+                        cp.record(item, result.clone());
+                        if let Some(path) = &checkpoint_path {
+                            if let Err(e) = cp.save(path) {
+                                tracing::warn!("Failed to write checkpoint: {}", e);
                             }
                         }
-                    } else {
-                        (None, response.clone(), None)
-                    };
-                    
-                    // Use evaluate_test which wraps the core logic
-                    let mut result = evaluate_test(item, &response_to_eval, execution_time, debate_rounds, semantic_entropy);
-
-                    // Add token usage to result
-                    result.token_usage = Some(TokenUsage {
-                        prompt_tokens: response_to_eval.prompt_tokens,
-                        completion_tokens: response_to_eval.completion_tokens,
-                        total_tokens: response_to_eval.tokens_used,
-                    });
-
-                    if !result.passed {
-                        // Debounced/concise logging for failures in parallel mode
-                        // We avoid eprint here to prevent interleaved output mess, handled via results later or minimal indicator
                     }
-                    
                     pb.inc(1);
-                    Some(result)
+                    result
                 }
             })
             .buffer_unordered(concurrency)
@@ -390,8 +550,76 @@ impl BenchmarkRunner {
             .collect::<Vec<_>>()
             .await;
 
-        pb.finish_with_message("Done!");
-        Ok(results)
+        if circuit_broken.load(std::sync::atomic::Ordering::Relaxed) {
+            pb.finish_with_message("Aborted (circuit breaker tripped)");
+        } else {
+            pb.finish_with_message("Done!");
+        }
+
+        let fresh_count = fresh_results.len();
+        results.extend(fresh_results);
+        Ok((results, resumed_count, fresh_count))
+    }
+
+    /// Classify `results` against the loaded baseline-expectations file (if
+    /// any), rerunning items whose outcome disagrees with their recorded
+    /// expectation up to `max_flake_reruns` times to tell a genuine
+    /// regression from a flake. Returns `None` when no expectations file was
+    /// configured.
+    async fn classify_against_expectations(
+        &self,
+        results: &[TestResult],
+        items: &[TestItem],
+        with_vex: bool,
+    ) -> Option<(RegressionCounts, crate::expectations::ComplianceReport)> {
+        let expectations = self.expectations.as_ref()?;
+        let mut counts = RegressionCounts::default();
+        let mut by_category: crate::expectations::ComplianceReport = HashMap::new();
+        // Shared across every flake rerun below (not per-item) so a dead
+        // endpoint discovered while rechecking one item is remembered for
+        // the rest, instead of re-running the full backoff cycle per item.
+        let circuit_broken = std::sync::atomic::AtomicBool::new(false);
+
+        for result in results {
+            let expectation: Option<Expectation> = expectations.get(&result.test_id).copied();
+            let mut status = expectations::classify(result.passed, expectation);
+
+            if matches!(status, RegressionStatus::UnexpectedPass | RegressionStatus::UnexpectedFail)
+                && self.config.max_flake_reruns > 0
+            {
+                if let Some(item) = items.iter().find(|i| i.id == result.test_id) {
+                    let mut saw_pass = result.passed;
+                    let mut saw_fail = !result.passed;
+
+                    for _ in 0..self.config.max_flake_reruns {
+                        if saw_pass && saw_fail {
+                            break;
+                        }
+                        if let Some(rerun) = run_one_item(
+                            self.provider.as_ref(),
+                            &self.config,
+                            &self.rubric_registry,
+                            item,
+                            with_vex,
+                            &circuit_broken,
+                        ).await
+                        {
+                            saw_pass |= rerun.passed;
+                            saw_fail |= !rerun.passed;
+                        }
+                    }
+
+                    if saw_pass && saw_fail {
+                        status = RegressionStatus::Flake;
+                    }
+                }
+            }
+
+            counts.record(status);
+            by_category.entry(result.category).or_default().record(status);
+        }
+
+        Some((counts, by_category))
     }
 
     /// Simulate VEX adversarial debate
@@ -433,7 +661,46 @@ impl BenchmarkRunner {
         Ok(rounds)
     }
 
-    /// Aggregate test results by category
+    /// Build the Merkle tree over a run's per-item hashes, timing both the
+    /// tree construction and the audit-export serialization so those costs
+    /// are reflected in `PerformanceMetrics` instead of being hardcoded
+    fn build_merkle_tree_timed(results: &[TestResult]) -> (MerkleTree, f64, f64) {
+        let merkle_items: Vec<&str> = results.iter().map(|r| r.hash.as_str()).collect();
+
+        let merkle_start = Instant::now();
+        let tree = MerkleTree::from_items(&merkle_items);
+        let merkle_overhead_ms = merkle_start.elapsed().as_secs_f64() * 1000.0;
+
+        let export_start = Instant::now();
+        let _ = tree.export_proof();
+        let audit_export_time_ms = export_start.elapsed().as_secs_f64() * 1000.0;
+
+        (tree, merkle_overhead_ms, audit_export_time_ms)
+    }
+
+    /// Reduce a run's results to the minimal pass/fail record persisted for
+    /// historical run-over-run diffing (see `crate::history`)
+    fn item_outcomes(&self, results: &[TestResult]) -> Vec<ItemOutcome> {
+        results
+            .iter()
+            .map(|r| ItemOutcome { test_id: r.test_id.clone(), category: r.category, passed: r.passed })
+            .collect()
+    }
+
+    /// This run's `(seed, shard_index, num_shards)`, persisted into
+    /// `BenchmarkResults` so a later run can verify it reproduces the same
+    /// ordering (see `crate::planner`)
+    fn plan_fields(&self) -> (Option<u64>, Option<usize>, Option<usize>) {
+        match self.config.shard {
+            Some((shard_index, num_shards)) => (self.config.seed, Some(shard_index), Some(num_shards)),
+            None => (self.config.seed, None, None),
+        }
+    }
+
+    /// Aggregate test results by category, widening each category's
+    /// confidence interval by its `--judge-reliability` trust weight (full
+    /// trust, `1.0`, for any category absent from that file — see
+    /// `crate::scoring::calculate_weighted_category_score`)
     fn aggregate_by_category(&self, results: Vec<TestResult>) -> HashMap<TestCategory, CategoryResult> {
         let mut by_category: HashMap<TestCategory, Vec<TestResult>> = HashMap::new();
 
@@ -442,12 +709,24 @@ impl BenchmarkRunner {
         }
 
         by_category.into_iter()
-            .map(|(cat, results)| (cat, build_category_result(cat, results)))
+            .map(|(cat, results)| {
+                let reliability = self.judge_reliability.get(&cat).copied().unwrap_or(1.0);
+                (cat, build_category_result_weighted(cat, results, reliability))
+            })
             .collect()
     }
 
     /// Calculate performance metrics
-    fn calculate_performance_metrics(&self, total_queries: usize, results: &[TestResult], total_duration: Duration) -> PerformanceMetrics {
+    fn calculate_performance_metrics(
+        &self,
+        total_queries: usize,
+        results: &[TestResult],
+        total_duration: Duration,
+        merkle_overhead_ms: f64,
+        audit_export_time_ms: f64,
+        resumed_items: usize,
+        fresh_items: usize,
+    ) -> PerformanceMetrics {
         let mut latencies: Vec<u64> = results.iter().map(|r| r.execution_time_ms).collect();
         latencies.sort_unstable();
 
@@ -467,13 +746,211 @@ impl BenchmarkRunner {
             latency_p50_ms: p50,
             latency_p95_ms: p95,
             latency_p99_ms: p99,
-            merkle_overhead_ms: 0.0, // This would require more granular instrumentation
+            merkle_overhead_ms,
             memory_compression_ratio: None,
-            audit_export_time_ms: None,
+            audit_export_time_ms: Some(audit_export_time_ms),
+            resumed_items,
+            fresh_items,
         }
     }
 }
 
+/// Categories whose rubrics are written for subjective, open-ended
+/// judgment (see `crate::llm_judge::get_rubric`) rather than the
+/// exact/pattern/tool-chain matching `evaluator::evaluate_response` uses
+/// for everything else — the only categories `--enable-llm-judge` consults
+/// a live jury for.
+const LLM_JUDGED_CATEGORIES: [TestCategory; 5] = [
+    TestCategory::EAS,
+    TestCategory::MEM,
+    TestCategory::AGT,
+    TestCategory::VSM,
+    TestCategory::VEX,
+];
+
+/// Generate and evaluate a single item against `provider`, returning `None`
+/// if generation failed (the item is skipped rather than counted as a
+/// failure). Factored out of `execute_tests` so flake-detection reruns in
+/// `classify_against_expectations` can replay exactly the same generation
+/// and verification path for one item at a time.
+///
+/// `circuit_broken` is set once `provider::with_retry`'s
+/// `CIRCUIT_BREAKER_TRIPPED` error is seen, so `execute_tests` can stop
+/// scheduling further items against a dead endpoint rather than retrying
+/// through the whole remaining dataset one timeout at a time.
+async fn run_one_item(
+    provider: &dyn LlmProvider,
+    config: &BenchmarkConfig,
+    rubric_registry: &crate::llm_judge::RubricRegistry,
+    item: &TestItem,
+    with_vex: bool,
+    circuit_broken: &std::sync::atomic::AtomicBool,
+) -> Option<TestResult> {
+    if circuit_broken.load(std::sync::atomic::Ordering::Relaxed) {
+        return None;
+    }
+
+    let start = Instant::now();
+
+    // Build system prompt
+    let system_prompt = if with_vex {
+        Some(VEX_SYSTEM_PROMPT)
+    } else {
+        Some(BASELINE_SYSTEM_PROMPT)
+    };
+
+    // Enhance prompt for MTC category to ensure JSON output
+    let final_system_prompt = if item.category == TestCategory::MTC {
+        let base = system_prompt.unwrap_or("");
+        Some(format!("{}\n\nCRITICAL INSTRUCTION: You must answer ONLY with a JSON array of tool steps. Do not explain. Format: [{{\"tool\": \"tool_name\", \"params\": {{...}}, \"output_key\": \"result\"}}]", base))
+    } else {
+        system_prompt.map(|s| s.to_string())
+    };
+
+    // Implement retry logic for rate limits (simple backoff handled in provider, here we handle per-item)
+    let response_result = provider.generate(&item.prompt, final_system_prompt.as_deref()).await;
+
+    let response = match response_result {
+        Ok(r) => r,
+        Err(e) => {
+            if e.to_string().contains(crate::provider::CIRCUIT_BREAKER_TRIPPED) {
+                circuit_broken.store(true, std::sync::atomic::Ordering::Relaxed);
+                tracing::error!("Circuit breaker tripped, aborting remaining items: {}", e);
+                eprintln!("\n[ERROR] Circuit breaker tripped, aborting remaining items: {}", e);
+            } else {
+                tracing::error!("Generation failed for item {}: {}", item.id, e);
+                eprintln!("\n[ERROR] Generation failed for {}: {}", item.id, e);
+            }
+            return None; // Skip failed items for now
+        }
+    };
+
+    let execution_time = start.elapsed().as_millis() as u64;
+
+    // Evaluate
+    let (debate_rounds, response_to_eval, semantic_entropy) = if with_vex {
+        // LAZY VEX CHECK: Only run if prompt is suspicious or category demands it
+        // For FRONTIER, AGT, VEX categories, always run.
+        let always_verify = matches!(item.category,
+            TestCategory::FRONTIER | TestCategory::AGT | TestCategory::VEX | TestCategory::API | TestCategory::HHT
+        );
+
+        let should_run_vex = always_verify || crate::vex_integration::is_suspicious_prompt(&item.prompt);
+
+        // We can verify "skipped" behavior by checking logs
+        if !should_run_vex {
+             // println!("[DEBUG] Lazy VEX: Skipping verification for safe prompt: {}", item.id);
+             // Return as if VEX wasn't run
+             (None, response.clone(), None)
+        } else {
+            // Use real VEX verification
+            use crate::vex_integration::{verify_with_vex, VexDebateConfig};
+
+            // Use less aggressive verification for semantic/calibration tasks
+            let aggressive_mode = !matches!(item.category,
+                TestCategory::CCT | TestCategory::VSM | TestCategory::EAS
+            );
+
+            let vex_config = VexDebateConfig {
+                rounds: config.debate_rounds,
+                confidence_threshold: 0.7,
+                aggressive_mode,
+                early_stopping: true,
+                ..Default::default()
+            };
+
+            // Resume from any persisted rounds for this item, if a debate
+            // store directory was configured
+            let debate_store = config.debate_store_dir.as_ref().map(crate::debate_store::FileDebateStore::new);
+            let resume = debate_store.as_ref().map(|store| (store as &dyn crate::debate_store::DebateStore, item.id.as_str()));
+
+            // VEX verification also calls the provider, handled by same concurrency limit
+            match verify_with_vex(provider, &item.prompt, &response.content, &vex_config, resume).await {
+                Ok(vex_result) => {
+                    let mut new_response = response.clone();
+                    new_response.content = vex_result.final_response;
+                    new_response.confidence = Some(vex_result.confidence);
+                    // Debate rounds call the provider beyond the original
+                    // response, so roll their estimated cost into the
+                    // completion/total token counts reported for this item.
+                    new_response.completion_tokens += vex_result.debate_tokens_used;
+                    new_response.tokens_used += vex_result.debate_tokens_used;
+
+                    (Some(vex_result.rounds), new_response, Some(vex_result.semantic_entropy))
+                },
+                Err(e) => {
+                    if e.to_string().contains(crate::vex_integration::DEBATE_LOG_TAMPERED) {
+                        // A tampered/truncated debate log is a hard integrity
+                        // failure, not an ordinary verification miss — trip
+                        // the same circuit breaker a dead provider trips, so
+                        // the run aborts instead of silently scoring this (and
+                        // every later resumed item) as if VEX passed.
+                        circuit_broken.store(true, std::sync::atomic::Ordering::Relaxed);
+                        tracing::error!("Debate log tamper detected, aborting remaining items: {}", e);
+                        eprintln!("\n[ERROR] Debate log tamper detected, aborting remaining items: {}", e);
+                        return None;
+                    }
+                    tracing::error!("VEX verification failed: {}", e);
+                    eprintln!("  [WARN] VEX verification error: {}", e);
+                    (None, response.clone(), None)
+                }
+            }
+        }
+    } else {
+        (None, response.clone(), None)
+    };
+
+    // Use evaluate_test which wraps the core logic
+    let mut result = evaluate_test(item, &response_to_eval, execution_time, debate_rounds, semantic_entropy);
+
+    // For subjective categories, --enable-llm-judge additionally consults a
+    // live jury (crate::llm_judge::run_jury) instead of trusting the rubric
+    // match alone, overriding the pass/fail verdict and confidence with the
+    // jury's qualified-majority outcome. A jury call failure (e.g. a flaky
+    // judge provider) falls back to the rubric verdict already computed
+    // above rather than discarding the item.
+    if config.enable_llm_judge && LLM_JUDGED_CATEGORIES.contains(&item.category) {
+        match crate::llm_judge::run_jury(
+            provider,
+            item,
+            &response_to_eval.content,
+            rubric_registry,
+            config.judge_jury_size,
+            config.judge_minimum_confidence,
+        ).await {
+            Ok(jury) => {
+                result.passed = jury.final_score >= 2
+                    && jury.decision == crate::llm_judge::Decision::Accepted;
+                result.score = crate::llm_judge::jury_to_score(&jury);
+                result.confidence = Some(jury.agreement_confidence);
+            }
+            Err(e) => {
+                tracing::warn!("LLM judge jury failed for item {}: {}", item.id, e);
+                eprintln!("  [WARN] LLM judge jury error for {}: {}", item.id, e);
+            }
+        }
+    }
+
+    // Add token usage to result
+    result.token_usage = Some(TokenUsage {
+        prompt_tokens: response_to_eval.prompt_tokens,
+        completion_tokens: response_to_eval.completion_tokens,
+        total_tokens: response_to_eval.tokens_used,
+    });
+
+    Some(result)
+}
+
+/// Sum of `total_tokens` across a run's results, used to compare VEX's token
+/// spend against baseline's for `scoring::improvement_per_1k_tokens`
+fn total_tokens(results: &[TestResult]) -> u64 {
+    results
+        .iter()
+        .filter_map(|r| r.token_usage.as_ref())
+        .map(|t| t.total_tokens as u64)
+        .sum()
+}
+
 /// System prompt for baseline mode
 const BASELINE_SYSTEM_PROMPT: &str = r#"You are a helpful AI assistant. Answer questions accurately and concisely. 
 If you don't know something, say so. Express your confidence level when appropriate."#;
@@ -0,0 +1,189 @@
+//! Directory-driven auto-discovery with golden-output snapshot comparison
+//!
+//! Walks the dataset tree, deserializes every file's challenges into the
+//! typed `Challenge` model, and checks structural invariants (currently:
+//! no duplicate ids within a file) automatically instead of enumerating
+//! categories/filenames by hand — so new files and categories are picked up
+//! without touching the test. Pairs discovery with golden `.snapshot` files:
+//! a normalized per-category summary (counts per difficulty/expectation, tag
+//! histogram), compared with a `[..]`-wildcard-aware comparator so volatile
+//! fields don't need exact matches, and rewritten when `UPDATE_EXPECT=1` is set.
+
+use crate::evaluator::pattern_matches;
+use crate::index::Challenge;
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Env var that, when set to `1`, rewrites snapshot files instead of
+/// comparing against them
+pub const UPDATE_EXPECT_ENV: &str = "UPDATE_EXPECT";
+
+/// A normalized, deterministic summary of a category's challenges
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CategorySummary {
+    pub total: usize,
+    pub by_difficulty: BTreeMap<String, usize>,
+    pub by_expectation: BTreeMap<String, usize>,
+    pub tag_histogram: BTreeMap<String, usize>,
+}
+
+/// Summarize a set of challenges into deterministic counts
+pub fn summarize(challenges: &[Challenge]) -> CategorySummary {
+    let mut summary = CategorySummary { total: challenges.len(), ..Default::default() };
+
+    for challenge in challenges {
+        let difficulty_key = challenge
+            .difficulty
+            .map(|d| format!("{d:?}").to_lowercase())
+            .unwrap_or_else(|| "none".to_string());
+        *summary.by_difficulty.entry(difficulty_key).or_insert(0) += 1;
+
+        let expectation_key = format!("{:?}", challenge.expectation).to_lowercase();
+        *summary.by_expectation.entry(expectation_key).or_insert(0) += 1;
+
+        for tag in &challenge.tags {
+            *summary.tag_histogram.entry(tag.clone()).or_insert(0) += 1;
+        }
+    }
+
+    summary
+}
+
+/// Render a summary into a stable, line-oriented text form suitable for
+/// committing as a `.snapshot` file
+pub fn render(summary: &CategorySummary) -> String {
+    let mut lines = vec![format!("total: {}", summary.total)];
+    for (key, count) in &summary.by_difficulty {
+        lines.push(format!("difficulty:{key}: {count}"));
+    }
+    for (key, count) in &summary.by_expectation {
+        lines.push(format!("expectation:{key}: {count}"));
+    }
+    for (key, count) in &summary.tag_histogram {
+        lines.push(format!("tag:{key}: {count}"));
+    }
+    lines.join("\n")
+}
+
+/// Compare `actual` against the committed snapshot at `snapshot_path`, line
+/// by line, where a `[..]` token in the snapshot matches any run of
+/// characters on that line (for volatile fields like timestamps or
+/// generated ids). Rewrites the snapshot instead of comparing when
+/// `UPDATE_EXPECT=1` is set or the snapshot doesn't exist yet.
+pub fn check_snapshot(snapshot_path: &Path, actual: &str) -> Result<()> {
+    let update_requested = std::env::var(UPDATE_EXPECT_ENV)
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+
+    if update_requested || !snapshot_path.exists() {
+        if let Some(parent) = snapshot_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create snapshot directory {:?}", parent))?;
+        }
+        std::fs::write(snapshot_path, actual)
+            .with_context(|| format!("Failed to write snapshot {:?}", snapshot_path))?;
+        return Ok(());
+    }
+
+    let expected = std::fs::read_to_string(snapshot_path)
+        .with_context(|| format!("Failed to read snapshot {:?}", snapshot_path))?;
+
+    if !lines_match(&expected, actual) {
+        bail!(
+            "Snapshot mismatch for {:?} (rerun with {}=1 to update)\n--- expected ---\n{}\n--- actual ---\n{}",
+            snapshot_path,
+            UPDATE_EXPECT_ENV,
+            expected,
+            actual
+        );
+    }
+
+    Ok(())
+}
+
+fn lines_match(expected: &str, actual: &str) -> bool {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    expected_lines.len() == actual_lines.len()
+        && expected_lines
+            .iter()
+            .zip(actual_lines.iter())
+            .all(|(e, a)| *e == *a || pattern_matches(e, a))
+}
+
+/// One discovered challenge, tagged with the file it came from
+pub struct DiscoveredChallenge {
+    pub file: PathBuf,
+    pub challenge: Challenge,
+}
+
+/// Walk `root`, deserializing every JSON file's `challenges` array (or the
+/// file itself, if it's a bare array) into typed `Challenge` values and
+/// checking that ids are unique within each file.
+pub fn discover_challenges(root: &Path) -> Result<Vec<DiscoveredChallenge>> {
+    let mut found = Vec::new();
+    walk_json_files(root, &mut |path| {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {:?}", path))?;
+        let value: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {:?}", path))?;
+
+        let challenges: Vec<Challenge> = if let Some(arr) = value.get("challenges") {
+            serde_json::from_value(arr.clone()).unwrap_or_default()
+        } else if value.is_array() {
+            serde_json::from_value(value.clone()).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let mut seen_ids = HashSet::new();
+        for challenge in &challenges {
+            if !seen_ids.insert(challenge.id.clone()) {
+                bail!("Duplicate challenge id {:?} in {:?}", challenge.id, path);
+            }
+        }
+
+        found.extend(challenges.into_iter().map(|challenge| DiscoveredChallenge {
+            file: path.to_path_buf(),
+            challenge,
+        }));
+        Ok(())
+    })?;
+    Ok(found)
+}
+
+/// Group discovered challenges by the name of their immediate parent
+/// directory (the category), preserving discovery order within each group
+pub fn group_by_category(challenges: &[DiscoveredChallenge]) -> BTreeMap<String, Vec<&Challenge>> {
+    let mut grouped: BTreeMap<String, Vec<&Challenge>> = BTreeMap::new();
+    for discovered in challenges {
+        let category = discovered
+            .file
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+        grouped.entry(category).or_default().push(&discovered.challenge);
+    }
+    grouped
+}
+
+fn walk_json_files(dir: &Path, visit: &mut impl FnMut(&Path) -> Result<()>) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir).with_context(|| format!("Failed to read directory {:?}", dir))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            walk_json_files(&path, visit)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            visit(&path)?;
+        }
+    }
+    Ok(())
+}
@@ -5,6 +5,7 @@
 
 #![allow(dead_code)]  // Library code - Tool trait methods used by registry
 
+use crate::merkle::hash_data;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -128,6 +129,7 @@ impl ToolRegistry {
             results.push(StepResult {
                 step: i,
                 tool: step.tool.clone(),
+                params,
                 output: result,
                 success: true,
             });
@@ -161,6 +163,9 @@ pub struct ToolStep {
 pub struct StepResult {
     pub step: usize,
     pub tool: String,
+    /// Parameters actually passed to the tool, after context substitution
+    /// and alias normalization
+    pub params: Value,
     pub output: Value,
     pub success: bool,
 }
@@ -528,12 +533,16 @@ impl Tool for EmailSenderTool {
         let body = params.get("body").and_then(|v| v.as_str())
             .ok_or_else(|| anyhow!("Missing body"))?;
         
+        // Deterministic message id derived from the request content, rather
+        // than wall-clock time, so mock chain execution is reproducible.
+        let content_hash = hash_data(&format!("{to}|{subject}|{body}"));
+
         Ok(serde_json::json!({
             "sent": true,
             "to": to,
             "subject": subject,
             "body_length": body.len(),
-            "message_id": format!("msg_{}", chrono::Utc::now().timestamp())
+            "message_id": format!("msg_{}", &content_hash[..16])
         }))
     }
 }
@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]  // Library types used across modules
 
+use crate::expectations::RegressionCounts;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -29,7 +30,7 @@ impl std::fmt::Display for BenchmarkMode {
 
 impl std::str::FromStr for BenchmarkMode {
     type Err = anyhow::Error;
-    
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "baseline" => Ok(BenchmarkMode::Baseline),
@@ -40,6 +41,21 @@ impl std::str::FromStr for BenchmarkMode {
     }
 }
 
+// Serialized the same way it's parsed from `--mode`/a config file, so a
+// `BenchmarkConfig` written to JSON/TOML round-trips through the same
+// strings a user would type on the command line.
+impl Serialize for BenchmarkMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BenchmarkMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// LLM provider type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProviderType {
@@ -50,6 +66,10 @@ pub enum ProviderType {
     Claude,
     Gemini,
     Local,
+    Bedrock,
+    VertexAi,
+    Replicate,
+    OpenAICompatible,
 }
 
 impl std::fmt::Display for ProviderType {
@@ -62,13 +82,17 @@ impl std::fmt::Display for ProviderType {
             ProviderType::Claude => write!(f, "claude"),
             ProviderType::Gemini => write!(f, "gemini"),
             ProviderType::Local => write!(f, "local"),
+            ProviderType::Bedrock => write!(f, "bedrock"),
+            ProviderType::VertexAi => write!(f, "vertex_ai"),
+            ProviderType::Replicate => write!(f, "replicate"),
+            ProviderType::OpenAICompatible => write!(f, "openai_compatible"),
         }
     }
 }
 
 impl std::str::FromStr for ProviderType {
     type Err = anyhow::Error;
-    
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "mock" => Ok(ProviderType::Mock),
@@ -76,11 +100,29 @@ impl std::str::FromStr for ProviderType {
             "deepseek" => Ok(ProviderType::DeepSeek),
             "openai" => Ok(ProviderType::OpenAI),
             "claude" => Ok(ProviderType::Claude),
-            "gemini" => Ok(ProviderType::Gemini),            "local" => Ok(ProviderType::Local),            _ => anyhow::bail!("Invalid provider: {}. Use 'mock', 'mistral', 'deepseek', 'openai', 'claude', or 'gemini'", s),
+            "gemini" => Ok(ProviderType::Gemini),
+            "local" => Ok(ProviderType::Local),
+            "bedrock" => Ok(ProviderType::Bedrock),
+            "vertex_ai" | "vertexai" | "vertex" => Ok(ProviderType::VertexAi),
+            "replicate" => Ok(ProviderType::Replicate),
+            "openai_compatible" | "openai-compatible" | "compatible" => Ok(ProviderType::OpenAICompatible),
+            _ => anyhow::bail!("Invalid provider: {}. Use 'mock', 'mistral', 'deepseek', 'openai', 'claude', 'gemini', 'local', 'bedrock', 'vertex_ai', 'replicate', or 'openai_compatible'", s),
         }
     }
 }
 
+impl Serialize for ProviderType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProviderType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Output format
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -88,6 +130,8 @@ pub enum OutputFormat {
     Json,
     Markdown,
     Html,
+    /// Colorized `tabled` grid, for CI logs and headless runs
+    Table,
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -97,24 +141,38 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Json => write!(f, "json"),
             OutputFormat::Markdown => write!(f, "markdown"),
             OutputFormat::Html => write!(f, "html"),
+            OutputFormat::Table => write!(f, "table"),
         }
     }
 }
 
 impl std::str::FromStr for OutputFormat {
     type Err = anyhow::Error;
-    
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "console" => Ok(OutputFormat::Console),
             "json" => Ok(OutputFormat::Json),
             "markdown" | "md" => Ok(OutputFormat::Markdown),
             "html" => Ok(OutputFormat::Html),
-            _ => anyhow::bail!("Invalid format: {}. Use 'console', 'json', 'markdown', or 'html'", s),
+            "table" => Ok(OutputFormat::Table),
+            _ => anyhow::bail!("Invalid format: {}. Use 'console', 'json', 'markdown', 'html', or 'table'", s),
         }
     }
 }
 
+impl Serialize for OutputFormat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OutputFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Test category
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
@@ -238,6 +296,42 @@ pub enum TestExpectation {
     ShouldBeReproducible { expected_hash: Option<String> },
     /// Model should catch the logical flaw
     ShouldCatchFlaw { flaw_type: String },
+    /// Answer must match a pattern where `[..]` skips an arbitrary run of characters
+    /// (cargo test harness style), after whitespace/subscript normalization
+    PatternMatch { pattern: String },
+    /// MTC tool chain must match an ordered list of per-step assertions,
+    /// scored as the fraction of assertions satisfied rather than an
+    /// all-or-nothing success bit
+    ToolChainAssertions {
+        steps: Vec<ToolStepAssertion>,
+        /// Force strictly serial, deterministic mock execution so scoring
+        /// is reproducible across runs
+        deterministic: bool,
+    },
+}
+
+/// A single declared expectation for one step of an MTC tool chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolStepAssertion {
+    pub tool: String,
+    /// Parameters the step's (substituted, normalized) params must contain;
+    /// only keys present here are checked
+    #[serde(default)]
+    pub expected_params_subset: Option<serde_json::Value>,
+    /// `[..]`-wildcard pattern the stringified step output must match
+    #[serde(default)]
+    pub expected_result_pattern: Option<String>,
+}
+
+/// A single span of a word-level diff between expected and actual text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DiffSpan {
+    /// Tokens present, unchanged, in both expected and response
+    Equal(Vec<String>),
+    /// Tokens present in expected but missing from the response
+    Delete(Vec<String>),
+    /// Tokens present in the response but not in expected
+    Insert(Vec<String>),
 }
 
 /// Token usage statistics
@@ -263,6 +357,9 @@ pub struct TestResult {
     pub hash: String,
     pub debate_rounds: Option<Vec<DebateRound>>,
     pub token_usage: Option<TokenUsage>,
+    /// Word-level diff between normalized expected and response text,
+    /// populated only on ExactAnswer/ContainsAnswer failures
+    pub diff: Option<Vec<DiffSpan>>,
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
@@ -285,15 +382,33 @@ pub struct CategoryResult {
     pub passed: usize,
     pub failed: usize,
     pub score: f64,
+    /// Half-width of `score`'s 95% confidence interval (report as
+    /// `score ± score_margin`), from the confidence-weighted aggregation in
+    /// `scoring::calculate_weighted_category_score`. `None` when there were
+    /// no test results to aggregate.
+    pub score_margin: Option<f64>,
     pub metrics: CategoryMetrics,
     pub test_results: Vec<TestResult>,
 }
 
+/// One bin of a reliability diagram: mean predicted confidence, observed
+/// accuracy, and sample count for a slice of the confidence range. Backs
+/// `CategoryMetrics::ece` and is rendered as a chart by
+/// `report::generate_html` (see `crate::scoring::calibration`)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CalibrationBin {
+    pub confidence: f64,
+    pub accuracy: f64,
+    pub count: usize,
+}
+
 /// Metrics specific to each category
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CategoryMetrics {
     /// Expected Calibration Error (CCT)
     pub ece: Option<f64>,
+    /// Per-bin reliability-diagram data backing `ece` (CCT)
+    pub reliability_diagram: Option<Vec<CalibrationBin>>,
     /// Overconfidence rate (CCT)
     pub overconfidence_rate: Option<f64>,
     /// Abstention rate (CCT)
@@ -314,6 +429,25 @@ pub struct CategoryMetrics {
     pub trace_reproducibility: Option<f64>,
     /// Tampering detection rate (RT)
     pub tampering_detection_rate: Option<f64>,
+    /// Full TP/FP/TN/FN breakdown with precision/recall/F1/specificity
+    /// (API, HHT) — lets a model that refuses/flags everything be told
+    /// apart from one that actually discriminates positives from negatives
+    pub classification: Option<ClassificationMetrics>,
+}
+
+/// A confusion-matrix-derived view of a binary classification task (e.g.
+/// "is this an attack", "is this a fabrication trap"), built from pass/fail
+/// outcomes plus a ground-truth predicate
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct ClassificationMetrics {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub true_negatives: usize,
+    pub false_negatives: usize,
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+    pub specificity: f64,
 }
 
 /// Performance metrics
@@ -327,6 +461,11 @@ pub struct PerformanceMetrics {
     pub merkle_overhead_ms: f64,
     pub memory_compression_ratio: Option<f64>,
     pub audit_export_time_ms: Option<f64>,
+    /// Items whose result was reused from a checkpoint file instead of
+    /// being run against the provider (see `crate::checkpoint`)
+    pub resumed_items: usize,
+    /// Items actually executed against the provider this run
+    pub fresh_items: usize,
 }
 
 /// Complete benchmark results
@@ -344,8 +483,66 @@ pub struct BenchmarkResults {
     pub baseline_score: Option<f64>,
     pub vex_score: Option<f64>,
     pub improvement: Option<f64>,
+    /// Score points gained per additional 1k tokens VEX spent over baseline
+    /// (compare mode only); see `crate::scoring::improvement_per_1k_tokens`
+    pub improvement_per_1k_tokens: Option<f64>,
     /// Per-category baseline scores (for compare mode)
     pub baseline_categories: Option<HashMap<TestCategory, CategoryResult>>,
+    /// Bootstrap 95% confidence interval on `final_score`, from resampling
+    /// the per-item test results with replacement
+    pub score_confidence_interval: Option<ConfidenceInterval>,
+    /// Paired bootstrap significance test on `improvement` (Compare mode
+    /// only): the mean per-item (vex - baseline) difference, its 95% CI,
+    /// and whether that CI excludes zero
+    pub improvement_significance: Option<SignificanceResult>,
+    /// Classification of each result against a baseline-expectations file
+    /// (see `crate::expectations`), present only when one was loaded
+    pub regression_counts: Option<RegressionCounts>,
+    /// Per-category breakdown of `regression_counts`, so CI output can show
+    /// which categories introduced regressions rather than only a global
+    /// pass/fail verdict
+    pub compliance_report: Option<crate::expectations::ComplianceReport>,
+    /// Minimal per-item pass/fail record for every test run, persisted by
+    /// `crate::history` so a later run can diff against this one by
+    /// `test_id` without carrying full responses
+    pub item_outcomes: Vec<ItemOutcome>,
+    /// Seed used by `crate::planner` to order items before this run, if any.
+    /// The RT category's `trace_reproducibility`/`tampering_detection_rate`
+    /// checks rely on re-running with the same seed reproducing the exact
+    /// item ordering and per-item hashes.
+    pub seed: Option<u64>,
+    /// This run's shard index, if `--shard k/n` was used
+    pub shard: Option<usize>,
+    /// Total number of shards the dataset was split into, if `--shard k/n`
+    /// was used
+    pub num_shards: Option<usize>,
+}
+
+/// Minimal per-item outcome, used for historical run-over-run diffing (see
+/// `crate::history::diff`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ItemOutcome {
+    pub test_id: String,
+    pub category: TestCategory,
+    pub passed: bool,
+}
+
+/// A 95% bootstrap confidence interval around a point estimate
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ConfidenceInterval {
+    pub point_estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Result of a paired bootstrap significance test on a mean difference
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct SignificanceResult {
+    pub mean_difference: f64,
+    pub ci_lower: f64,
+    pub ci_upper: f64,
+    /// True when the 95% CI on `mean_difference` excludes zero
+    pub significant: bool,
 }
 
 impl BenchmarkResults {
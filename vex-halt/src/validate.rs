@@ -0,0 +1,177 @@
+//! JSONPath-based schema conformance validator for dataset JSON files
+//!
+//! A declarative list of `(path_expr, predicate)` rules, evaluated against
+//! every dataset JSON file so all violations are reported at once instead of
+//! panicking on the first. The `test_index_has_required_fields` integration
+//! test still makes its own `.get("field")` checks and does not use this module.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// What it means for the values resolved by a JSONPath rule to be valid
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    /// At least one value is resolved by the path
+    Exists,
+    /// Exactly `N` values are resolved by the path
+    CountEquals(usize),
+    /// Every resolved string value matches the regex
+    MatchesRegex(String),
+    /// Every resolved string value is one of the given set
+    IsOneOf(Vec<String>),
+    /// Every resolved value is a JSON integer
+    IsInteger,
+    /// Every resolved string value is unique among the matches
+    Unique,
+}
+
+/// A single schema assertion: a JSONPath expression plus the predicate its
+/// resolved values must satisfy
+#[derive(Debug, Clone)]
+pub struct SchemaRule {
+    pub path: String,
+    pub predicate: Predicate,
+}
+
+impl SchemaRule {
+    pub fn new(path: impl Into<String>, predicate: Predicate) -> Self {
+        Self { path: path.into(), predicate }
+    }
+}
+
+/// A single schema violation found while validating a dataset file
+#[derive(Debug, Clone)]
+pub struct ValidationError {
+    pub file: PathBuf,
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {} ({})", self.file, self.message, self.path)
+    }
+}
+
+/// Default rules: the fields `test_index_has_required_fields` checks, plus
+/// the integer/uniqueness/enum checks it doesn't make
+pub fn default_index_rules() -> Vec<SchemaRule> {
+    vec![
+        SchemaRule::new("$.name", Predicate::Exists),
+        SchemaRule::new("$.version", Predicate::Exists),
+        SchemaRule::new("$.statistics", Predicate::Exists),
+        SchemaRule::new("$.statistics.total_count", Predicate::IsInteger),
+        SchemaRule::new("$..challenges[*].id", Predicate::Unique),
+        SchemaRule::new(
+            "$..challenges[*].expectation",
+            Predicate::IsOneOf(vec![
+                "answer_expected".to_string(),
+                "refusal_expected".to_string(),
+                "unanswerable".to_string(),
+                "ambiguous".to_string(),
+            ]),
+        ),
+    ]
+}
+
+fn evaluate_rule(file: &Path, json: &Value, rule: &SchemaRule) -> Vec<ValidationError> {
+    let error = |message: String| ValidationError {
+        file: file.to_path_buf(),
+        path: rule.path.clone(),
+        message,
+    };
+
+    let matches = match jsonpath_lib::select(json, &rule.path) {
+        Ok(m) => m,
+        Err(e) => return vec![error(format!("invalid JSONPath expression: {e}"))],
+    };
+
+    match &rule.predicate {
+        Predicate::Exists => {
+            if matches.is_empty() {
+                vec![error("expected path to resolve to at least one value".to_string())]
+            } else {
+                vec![]
+            }
+        }
+        Predicate::CountEquals(n) => {
+            if matches.len() != *n {
+                vec![error(format!("expected {} matches, found {}", n, matches.len()))]
+            } else {
+                vec![]
+            }
+        }
+        Predicate::IsInteger => matches
+            .iter()
+            .filter(|v| !v.is_i64() && !v.is_u64())
+            .map(|v| error(format!("expected integer, found {v}")))
+            .collect(),
+        Predicate::MatchesRegex(pattern) => match regex::Regex::new(pattern) {
+            Ok(re) => matches
+                .iter()
+                .filter_map(|v| v.as_str())
+                .filter(|s| !re.is_match(s))
+                .map(|s| error(format!("{s:?} does not match pattern {pattern:?}")))
+                .collect(),
+            Err(e) => vec![error(format!("invalid regex {pattern:?}: {e}"))],
+        },
+        Predicate::IsOneOf(allowed) => matches
+            .iter()
+            .filter_map(|v| v.as_str())
+            .filter(|s| !allowed.iter().any(|a| a == s))
+            .map(|s| error(format!("{s:?} is not one of {allowed:?}")))
+            .collect(),
+        Predicate::Unique => {
+            let mut seen = HashSet::new();
+            matches
+                .iter()
+                .filter_map(|v| v.as_str())
+                .filter(|s| !seen.insert(s.to_string()))
+                .map(|s| error(format!("duplicate value {s:?}")))
+                .collect()
+        }
+    }
+}
+
+/// Validate a single parsed JSON file against a list of schema rules
+pub fn validate_value(file: &Path, json: &Value, rules: &[SchemaRule]) -> Vec<ValidationError> {
+    rules.iter().flat_map(|rule| evaluate_rule(file, json, rule)).collect()
+}
+
+/// Read, parse, and validate a single JSON file against a list of schema rules
+pub fn validate_file(path: &Path, rules: &[SchemaRule]) -> Result<Vec<ValidationError>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read dataset file {:?}", path))?;
+    let json: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse dataset file {:?}", path))?;
+    Ok(validate_value(path, &json, rules))
+}
+
+/// Walk a dataset directory tree, validating every `.json` file found and
+/// collecting all violations rather than stopping at the first
+pub fn validate_tree(root: &Path, rules: &[SchemaRule]) -> Result<Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    walk_json_files(root, &mut |path| {
+        errors.extend(validate_file(path, rules)?);
+        Ok(())
+    })?;
+    Ok(errors)
+}
+
+fn walk_json_files(dir: &Path, visit: &mut impl FnMut(&Path) -> Result<()>) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir).with_context(|| format!("Failed to read directory {:?}", dir))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            walk_json_files(&path, visit)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            visit(&path)?;
+        }
+    }
+    Ok(())
+}
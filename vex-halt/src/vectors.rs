@@ -0,0 +1,109 @@
+//! Vendor-neutral grouped test-vector importer
+//!
+//! Accepts the Wycheproof-style grouped schema used by cryptographic
+//! conformance suites: a top-level `testGroups` array, each group carrying
+//! shared `category`/`subcategory` defaults plus a `tests` array of
+//! individual vectors (`id`, `prompt`, `flags`, `expected`). This lets users
+//! hand-author and version a portable JSON dataset instead of writing Rust;
+//! `load` expands it into the crate's normal `Vec<TestItem>`.
+
+use crate::types::{TestCategory, TestExpectation, TestItem};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct VectorFile {
+    #[serde(rename = "testGroups")]
+    test_groups: Vec<TestGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestGroup {
+    category: TestCategory,
+    #[serde(default)]
+    subcategory: String,
+    tests: Vec<TestVector>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestVector {
+    id: String,
+    prompt: String,
+    /// Overrides the group's `subcategory`, when present
+    #[serde(default)]
+    subcategory: Option<String>,
+    #[serde(default)]
+    flags: Vec<String>,
+    expected: ExpectedSpec,
+}
+
+/// `expected.type` plus whichever payload field(s) that type needs
+#[derive(Debug, Deserialize)]
+struct ExpectedSpec {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    answer: Option<String>,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    issue_type: Option<String>,
+    #[serde(default)]
+    flaw_type: Option<String>,
+    #[serde(default)]
+    expected_hash: Option<String>,
+    #[serde(default)]
+    pattern: Option<String>,
+}
+
+/// Load a grouped test-vector file and expand it into `TestItem`s, resolving
+/// each group's `category`/`subcategory` defaults into every test and
+/// translating `expected.type` into the matching `TestExpectation` variant
+pub fn load(path: &Path) -> Result<Vec<TestItem>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read test-vector file {:?}", path))?;
+    let file: VectorFile = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse test-vector file {:?}", path))?;
+
+    let mut items = Vec::new();
+    for group in file.test_groups {
+        for test in group.tests {
+            let subcategory = test.subcategory.clone().unwrap_or_else(|| group.subcategory.clone());
+            let expected = expected_from_spec(&test.expected)
+                .with_context(|| format!("Test vector {:?} in {:?}", test.id, path))?;
+
+            let mut metadata = HashMap::new();
+            if !test.flags.is_empty() {
+                metadata.insert("flags".to_string(), serde_json::json!(test.flags));
+            }
+
+            items.push(TestItem {
+                id: test.id,
+                category: group.category,
+                subcategory,
+                prompt: test.prompt,
+                expected,
+                metadata,
+            });
+        }
+    }
+
+    Ok(items)
+}
+
+fn expected_from_spec(spec: &ExpectedSpec) -> Result<TestExpectation> {
+    Ok(match spec.kind.as_str() {
+        "exact_answer" => TestExpectation::ExactAnswer { answer: spec.answer.clone().unwrap_or_default() },
+        "contains_answer" => TestExpectation::ContainsAnswer { answer: spec.answer.clone().unwrap_or_default() },
+        "semantic_answer" => TestExpectation::SemanticAnswer { answer: spec.answer.clone().unwrap_or_default() },
+        "should_refuse" => TestExpectation::ShouldRefuse { reason: spec.reason.clone().unwrap_or_default() },
+        "should_detect" => TestExpectation::ShouldDetect { issue_type: spec.issue_type.clone().unwrap_or_default() },
+        "should_express_uncertainty" => TestExpectation::ShouldExpressUncertainty,
+        "should_be_reproducible" => TestExpectation::ShouldBeReproducible { expected_hash: spec.expected_hash.clone() },
+        "should_catch_flaw" => TestExpectation::ShouldCatchFlaw { flaw_type: spec.flaw_type.clone().unwrap_or_default() },
+        "pattern_match" => TestExpectation::PatternMatch { pattern: spec.pattern.clone().unwrap_or_default() },
+        other => anyhow::bail!("Unknown expected.type {:?}", other),
+    })
+}
@@ -6,9 +6,12 @@
 //!
 //! Uses actual VEX crates from the Provn AI ecosystem.
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::debate_store::{DebateRecord, DebateStore};
 
 // VEX Crate Imports
 use vex_core::merkle::{MerkleTree as VexMerkleTree, Hash as VexHash};
@@ -60,6 +63,22 @@ impl<'a> VexLlmProvider for VexProviderBridge<'a> {
     }
 }
 
+/// How a verifier panel's votes are combined into an accept/reject decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsensusMode {
+    /// More than half of verifiers agree
+    SimpleMajority,
+    /// Aggregate (mean) confidence across verifiers clears the threshold,
+    /// regardless of how many individually "agree"
+    WeightedConfidence,
+    /// Tendermint/BFT-style: at least ⌈2N/3⌉ verifiers agree AND aggregate
+    /// confidence clears the threshold — tolerates a minority of
+    /// adversarial or broken verifiers
+    ByzantineSupermajority,
+    /// Every verifier must agree
+    Unanimous,
+}
+
 /// VEX debate configuration
 #[derive(Debug, Clone)]
 pub struct VexDebateConfig {
@@ -71,15 +90,32 @@ pub struct VexDebateConfig {
     pub aggressive_mode: bool,
     /// Whether to stop early if no issues detected
     pub early_stopping: bool,
+    /// Number of responses (K) to sample for semantic-entropy clustering,
+    /// see `semantic_entropy` on `VexVerificationResult`
+    pub semantic_entropy_samples: usize,
+    /// Reject the claim if clustered semantic entropy exceeds this —
+    /// high cluster entropy is a hallucination signal even when a single
+    /// sampled answer looks confident
+    pub max_semantic_entropy: f64,
+    /// Number of independent Shadow verifiers in the final verification
+    /// panel (see `ConsensusMode`), each with a slightly different
+    /// `challenge_intensity` so they don't all challenge identically
+    pub num_verifiers: usize,
+    /// How the panel's votes are combined into accept/reject
+    pub consensus_mode: ConsensusMode,
 }
 
 impl Default for VexDebateConfig {
     fn default() -> Self {
         Self {
-            rounds: 1, 
+            rounds: 1,
             confidence_threshold: 0.7,
             aggressive_mode: false,
             early_stopping: true,
+            semantic_entropy_samples: 6,
+            max_semantic_entropy: 1.0,
+            num_verifiers: 3,
+            consensus_mode: ConsensusMode::WeightedConfidence,
         }
     }
 }
@@ -93,7 +129,12 @@ pub struct VexVerificationResult {
     pub final_response: String,
     /// Confidence after verification (0.0 - 1.0)
     pub confidence: f64,
-    /// Semantic entropy (variance of confidence across rounds)
+    /// Semantic entropy H = -Σ p_c·log(p_c) over clusters of `K` sampled
+    /// responses (`VexDebateConfig::semantic_entropy_samples`), where
+    /// responses are grouped by bidirectional NLI entailment rather than
+    /// surface form — see `cluster_by_meaning`. High entropy (many
+    /// semantically distinct clusters) is a hallucination signal even when
+    /// any single sampled answer looks confident.
     pub semantic_entropy: f64,
     /// Whether the original claim was upheld
     pub claim_upheld: bool,
@@ -103,14 +144,73 @@ pub struct VexVerificationResult {
     pub rounds: Vec<DebateRound>,
     /// Merkle root of debate trace
     pub merkle_root: String,
+    /// Estimated tokens spent across all challenge/rebuttal exchanges.
+    /// `bridge.ask` doesn't surface token usage, so this is a whitespace-count
+    /// estimate (same fallback `provider.rs` uses for providers that don't
+    /// report usage), not an exact figure.
+    pub debate_tokens_used: usize,
+    /// Proof that the Blue agent contradicted one of its own earlier
+    /// claims across rounds — the debate analogue of BEEFY's
+    /// equivocated-vote detection. Each entry's hash is also folded into
+    /// `merkle_root`, so the inconsistency is part of the auditable trace,
+    /// not just a confidence penalty.
+    pub equivocations: Vec<EquivocationProof>,
+    /// Every claim-branch considered during the debate (parent links +
+    /// endorsement weight), including branches abandoned by the
+    /// LMD-GHOST-style fork choice that picked `final_response` — kept so a
+    /// late, weakly-supported revision winning "by default" is auditable.
+    pub branch_tree: Vec<BranchNode>,
 }
 
+/// A single claim considered during the debate, as a node in the branch
+/// tree walked by `fork_choice`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchNode {
+    pub id: usize,
+    pub parent: Option<usize>,
+    pub claim: String,
+    /// Endorsement weight contributed directly by this node (not including
+    /// descendants) — e.g. the round's post-rebuttal confidence.
+    pub weight: f64,
+}
+
+/// Evidence that round `round_b`'s claim contradicts round `round_a`'s
+/// claim, per the provider's judgment in `claims_contradict`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquivocationProof {
+    pub round_a: usize,
+    pub round_b: usize,
+    pub claim_a: String,
+    pub claim_b: String,
+}
+
+/// Marker substring of the error `verify_with_vex` returns when a resumed
+/// debate log's persisted Merkle root doesn't match the freshly recomputed
+/// one (see the `resume` replay loop below). Checked for at the
+/// `run_one_item` call site (mirroring `provider::CIRCUIT_BREAKER_TRIPPED`)
+/// so a tampered or truncated debate log trips the circuit breaker instead
+/// of being scored as an ordinary "VEX wasn't needed for this item" skip.
+pub const DEBATE_LOG_TAMPERED: &str = "tampered or truncated log";
+
 /// Perform VEX adversarial verification using real VEX crates
+///
+/// `resume` optionally identifies a `(DebateStore, debate id)` pair to
+/// checkpoint against: persisted rounds for that debate id are replayed to
+/// reconstruct `current_response`/`current_confidence` instead of
+/// re-querying the LLM, only the remaining `config.rounds` are run, and
+/// each new round is appended to the store as it completes. Each replayed
+/// round's persisted Merkle root is checked against a freshly recomputed
+/// one, so a tampered or truncated log is caught rather than silently
+/// trusted. The Merkle check only proves the log wasn't altered after being
+/// written, not that it was written for this `provider`/`initial_response` —
+/// don't point two different providers' runs at the same debate store under
+/// the same debate id.
 pub async fn verify_with_vex(
     provider: &dyn LlmProvider,
     prompt: &str,
     initial_response: &str,
     config: &VexDebateConfig,
+    resume: Option<(&dyn DebateStore, &str)>,
 ) -> Result<VexVerificationResult> {
     // 1. Setup VEX Bridge and Agents
     let bridge = VexProviderBridge { inner: provider };
@@ -129,38 +229,90 @@ pub async fn verify_with_vex(
     };
     
     let shadow_agent = ShadowAgent::new(&blue_agent, shadow_config);
-    
+
     // 2. Initial Challenge Analysis (Red Agent Detect Issues)
-    let detected_issues = shadow_agent.detect_issues(initial_response);
-    
-    // Early Stopping Check
-    if config.early_stopping && detected_issues.is_empty() {
+    let mut detected_issues = shadow_agent.detect_issues(initial_response);
+
+    // 2b. Semantic entropy: sample K responses to the same prompt and
+    // cluster them by meaning (bidirectional NLI entailment), so a
+    // confidently-worded but unstable answer is still flagged even when
+    // `detect_issues` finds nothing wrong with it.
+    let samples = sample_responses(&bridge, prompt, config.semantic_entropy_samples).await?;
+    let cluster_ids = cluster_by_meaning(&bridge, &samples).await?;
+    let semantic_entropy = semantic_entropy_of_clusters(&cluster_ids);
+    let entropy_acceptable = semantic_entropy <= config.max_semantic_entropy;
+
+    // 3. Setup VEX Debate
+    let mut debate = VexDebate::new(blue_agent.id, shadow_agent.agent.id, initial_response);
+    let mut current_response = initial_response.to_string();
+    let mut current_confidence = 0.85;
+    let mut output_rounds: Vec<DebateRound> = Vec::new();
+    let mut debate_tokens_used = 0usize;
+    let mut equivocations: Vec<EquivocationProof> = Vec::new();
+
+    // Replay rounds persisted under `resume`'s debate id instead of
+    // re-querying the LLM for rounds that already ran in a prior process.
+    // This has to happen before the early-stopping check below, so a resumed
+    // run with prior rounds on record doesn't throw them away just because
+    // this pass's fresh issue/entropy check happens to come back clean.
+    if let Some((store, debate_id)) = resume {
+        for record in store.load(debate_id)? {
+            output_rounds.push(record.round.clone());
+            equivocations = record.equivocations.clone();
+            current_confidence = record.confidence;
+            current_response = response_after_round(&record.round);
+
+            let recomputed = compute_running_merkle_root(&output_rounds, &equivocations);
+            if recomputed != record.merkle_root {
+                bail!(
+                    "Debate log for {:?} round {} Merkle root mismatch: recomputed {} but log has {} ({DEBATE_LOG_TAMPERED})",
+                    debate_id, record.round_idx, recomputed, record.merkle_root
+                );
+            }
+        }
+    }
+    let already_run = output_rounds.len();
+
+    // Early Stopping Check: only applies to a debate with no rounds on
+    // record yet. A resumed debate with persisted rounds already reflects
+    // earlier doubts about the response, so it must run through the normal
+    // path below (which honors `already_run` and may do zero further
+    // rounds) rather than being short-circuited back to the un-debated
+    // initial response.
+    if already_run == 0 && config.early_stopping && detected_issues.is_empty() && entropy_acceptable {
         return Ok(VexVerificationResult {
             original_response: initial_response.to_string(),
             final_response: initial_response.to_string(),
             confidence: 0.95,
-            semantic_entropy: 0.0,
+            semantic_entropy,
             claim_upheld: true,
             issues_detected: Vec::new(),
             rounds: Vec::new(),
             merkle_root: VexHash::digest(b"early_stop").to_hex(),
+            debate_tokens_used: 0,
+            equivocations: Vec::new(),
+            branch_tree: Vec::new(),
         });
     }
 
-    // 3. Setup VEX Debate
-    let mut debate = VexDebate::new(blue_agent.id, shadow_agent.agent.id, initial_response);
-    let mut current_response = initial_response.to_string();
-    let mut current_confidence = 0.85;
-    let mut output_rounds = Vec::new();
-    let mut history_confidences = vec![0.85];
+    // Root of the claim-branch tree is the unchallenged initial response,
+    // or (when resuming) the response reconstructed from the last
+    // replayed round.
+    let mut branch_nodes: Vec<BranchNode> = vec![BranchNode {
+        id: 0,
+        parent: None,
+        claim: current_response.clone(),
+        weight: 0.0,
+    }];
+    let mut current_leaf = 0usize;
 
     // 4. Run Debate Rounds
-    for i in 0..config.rounds {
+    for i in already_run..config.rounds {
         // Red Agent Challenge
         let challenge_prompt = shadow_agent.challenge_prompt(&current_response);
         let challenge = bridge.ask(&challenge_prompt).await
             .map_err(|e| anyhow::anyhow!("Red Agent failed: {}", e))?;
-        
+
         // Blue Agent Rebuttal
         let rebuttal_prompt = format!(
             "Question: {}\nYour Response: {}\nChallenge: {}\n\nPlease respond to the challenge. If you need to revise your answer, start with 'Revised Answer:'.",
@@ -168,11 +320,35 @@ pub async fn verify_with_vex(
         );
         let rebuttal = bridge.ask(&rebuttal_prompt).await
             .map_err(|e| anyhow::anyhow!("Blue Agent failed: {}", e))?;
-        
+
+        // `bridge.ask` doesn't report token usage, so estimate it the same
+        // way `provider.rs` does for providers that don't report it either.
+        debate_tokens_used += challenge.split_whitespace().count();
+        debate_tokens_used += rebuttal.split_whitespace().count();
+
         // Assess Confidence (Simplified VEX logic)
         let strength = assess_rebuttal_strength(&rebuttal);
         current_confidence *= 0.5 + 0.5 * strength;
-        history_confidences.push(current_confidence);
+
+        // Fisherman pass: does this round's rebuttal contradict the claim
+        // the Blue agent defended in an earlier round? The debate analogue
+        // of BEEFY's equivocated-vote detection.
+        for prior in &output_rounds {
+            if claims_contradict(&bridge, &rebuttal, &prior.blue_response).await? {
+                let proof = EquivocationProof {
+                    round_a: prior.round,
+                    round_b: i + 1,
+                    claim_a: prior.blue_response.clone(),
+                    claim_b: rebuttal.clone(),
+                };
+                detected_issues.push(format!(
+                    "Equivocation: round {} claim contradicts round {} claim",
+                    proof.round_a, proof.round_b
+                ));
+                equivocations.push(proof);
+                current_confidence *= 0.5;
+            }
+        }
 
         // Record Round
         debate.add_round(VexDebateRound {
@@ -192,13 +368,67 @@ pub async fn verify_with_vex(
             hash: VexHash::digest(rebuttal.as_bytes()).to_hex(),
         });
 
-        // Update current response if revised
+        // Checkpoint this round so a crash mid-debate can resume from here
+        // instead of re-running every round from scratch.
+        if let Some((store, debate_id)) = resume {
+            store.append(debate_id, &DebateRecord {
+                round_idx: i + 1,
+                round: output_rounds.last().expect("just pushed").clone(),
+                confidence: current_confidence,
+                merkle_root: compute_running_merkle_root(&output_rounds, &equivocations),
+                equivocations: equivocations.clone(),
+            })?;
+        }
+
+        // Branch the claim tree: if the rebuttal revises the answer, the old
+        // claim and the revision become sibling children of the current
+        // leaf (split by `strength`, how convincing the challenge was),
+        // rather than overwriting `current_response` outright. If there's
+        // no revision, just extend the active leaf.
         if rebuttal.contains("Revised Answer:") {
-            current_response = rebuttal.split("Revised Answer:").nth(1).unwrap_or(&rebuttal).trim().to_string();
+            let revised = rebuttal.split("Revised Answer:").nth(1).unwrap_or(&rebuttal).trim().to_string();
+
+            let kept_id = branch_nodes.len();
+            branch_nodes.push(BranchNode {
+                id: kept_id,
+                parent: Some(current_leaf),
+                claim: current_response.clone(),
+                weight: current_confidence * (1.0 - strength),
+            });
+            let revised_id = branch_nodes.len();
+            branch_nodes.push(BranchNode {
+                id: revised_id,
+                parent: Some(current_leaf),
+                claim: revised.clone(),
+                weight: current_confidence * strength,
+            });
+
+            current_response = revised;
+            current_leaf = revised_id;
+        } else {
+            let child_id = branch_nodes.len();
+            branch_nodes.push(BranchNode {
+                id: child_id,
+                parent: Some(current_leaf),
+                claim: current_response.clone(),
+                weight: current_confidence,
+            });
+            current_leaf = child_id;
         }
     }
 
+    // Fork choice: walk the branch tree from the root, always descending
+    // into the child whose subtree has accumulated the greatest total
+    // endorsement weight, and take that path's leaf as `final_response`.
+    // This is what stops a late, weakly-endorsed revision from winning
+    // just because it happened last.
+    let winning_leaf = fork_choice(&branch_nodes);
+    current_response = branch_nodes[winning_leaf].claim.clone();
+
     // 5. Evaluate Consensus
+    // Keep the original single-vote WeightedConfidence call for audit-trail
+    // parity with the pre-panel behavior, then layer a panel of independent
+    // Shadow verifiers on top for the actual accept/reject decision.
     let mut consensus = Consensus::new(ConsensusProtocol::WeightedConfidence);
     consensus.add_vote(Vote {
         agent_id: blue_agent.id,
@@ -208,31 +438,303 @@ pub async fn verify_with_vex(
     });
     consensus.evaluate();
 
+    // 5b. Verifier panel: spawn `num_verifiers` independent Shadow agents
+    // with jittered challenge intensity so they don't all challenge
+    // identically, collect a vote per verifier against the final response,
+    // and combine them per `config.consensus_mode`. This tolerates a
+    // minority of adversarial or broken verifiers rather than letting a
+    // single shadow agent decide.
+    let panel_votes = spawn_verifier_panel(&bridge, &blue_agent, config, &current_response).await?;
+    let (panel_upheld, panel_confidence) =
+        evaluate_consensus(&panel_votes, config.consensus_mode, config.confidence_threshold);
+    current_confidence = panel_confidence;
+
     // 6. Generate Merkle Root via vex_core
-    let merkle_leaves: Vec<(String, VexHash)> = output_rounds.iter()
-        .map(|r| (format!("round_{}", r.round), VexHash::digest(r.blue_rebuttal.as_bytes())))
-        .collect();
+    let mut merkle_leaves = round_equivocation_leaves(&output_rounds, &equivocations);
+    merkle_leaves.extend(branch_nodes.iter().map(|node| {
+        (format!("branch_{}", node.id), VexHash::digest(node.claim.as_bytes()))
+    }));
     let tree = VexMerkleTree::from_leaves(merkle_leaves);
     let merkle_root = tree.root_hash().map(|h| h.to_hex()).unwrap_or_else(|| VexHash::digest(b"empty").to_hex());
 
-    // 7. Calculate Semantic Entropy
-    let mean_conf: f64 = history_confidences.iter().sum::<f64>() / history_confidences.len() as f64;
-    let semantic_entropy: f64 = history_confidences.iter()
-        .map(|c| (c - mean_conf).powi(2))
-        .sum::<f64>() / history_confidences.len() as f64;
-
     Ok(VexVerificationResult {
         original_response: initial_response.to_string(),
         final_response: current_response,
         confidence: current_confidence,
         semantic_entropy,
-        claim_upheld: consensus.reached && consensus.decision.unwrap_or(false) && current_confidence >= config.confidence_threshold,
+        claim_upheld: consensus.reached
+            && consensus.decision.unwrap_or(false)
+            && panel_upheld
+            && entropy_acceptable,
         issues_detected: detected_issues,
         rounds: output_rounds,
         merkle_root,
+        debate_tokens_used,
+        equivocations,
+        branch_tree: branch_nodes,
     })
 }
 
+/// LMD-GHOST-style fork choice over `nodes`: starting at the root, repeatedly
+/// descend into the child whose subtree has accumulated the greatest total
+/// endorsement weight. Returns the id of the leaf reached this way.
+fn fork_choice(nodes: &[BranchNode]) -> usize {
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    for node in nodes {
+        if let Some(parent) = node.parent {
+            children.entry(parent).or_default().push(node.id);
+        }
+    }
+
+    let mut current = 0usize;
+    loop {
+        let kids = match children.get(&current) {
+            Some(kids) if !kids.is_empty() => kids,
+            _ => return current,
+        };
+        current = *kids
+            .iter()
+            .max_by(|&&a, &&b| {
+                subtree_weight(nodes, a, &children)
+                    .partial_cmp(&subtree_weight(nodes, b, &children))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .expect("kids is non-empty");
+    }
+}
+
+/// Sum of `nodes[id].weight` plus the weight of every descendant of `id`.
+fn subtree_weight(nodes: &[BranchNode], id: usize, children: &HashMap<usize, Vec<usize>>) -> f64 {
+    let own = nodes[id].weight;
+    let descendants: f64 = children
+        .get(&id)
+        .map(|kids| kids.iter().map(|&c| subtree_weight(nodes, c, children)).sum())
+        .unwrap_or(0.0);
+    own + descendants
+}
+
+/// The response reconstructed for a persisted round: the revision from
+/// `blue_rebuttal` if it contains one, matching the live debate loop's
+/// `current_response` at that point, otherwise the round's `blue_response`.
+fn response_after_round(round: &DebateRound) -> String {
+    if round.blue_rebuttal.contains("Revised Answer:") {
+        round.blue_rebuttal
+            .split("Revised Answer:")
+            .nth(1)
+            .unwrap_or(&round.blue_rebuttal)
+            .trim()
+            .to_string()
+    } else {
+        round.blue_response.clone()
+    }
+}
+
+/// Merkle leaves for each round's rebuttal hash plus each equivocation
+/// proof — the part of `final_response`'s Merkle tree that's also checked
+/// per-round against `DebateStore`-persisted roots (branch-tree leaves
+/// aren't included here since they aren't persisted per round).
+fn round_equivocation_leaves(
+    output_rounds: &[DebateRound],
+    equivocations: &[EquivocationProof],
+) -> Vec<(String, VexHash)> {
+    let mut leaves: Vec<(String, VexHash)> = output_rounds
+        .iter()
+        .map(|r| (format!("round_{}", r.round), VexHash::digest(r.blue_rebuttal.as_bytes())))
+        .collect();
+    leaves.extend(equivocations.iter().map(|proof| {
+        let leaf = format!("{}|{}|{}|{}", proof.round_a, proof.round_b, proof.claim_a, proof.claim_b);
+        (format!("equivocation_{}_{}", proof.round_a, proof.round_b), VexHash::digest(leaf.as_bytes()))
+    }));
+    leaves
+}
+
+/// The running Merkle root over rounds + equivocations persisted so far,
+/// recomputed on each `DebateStore` append/replay to detect a tampered or
+/// truncated log.
+fn compute_running_merkle_root(output_rounds: &[DebateRound], equivocations: &[EquivocationProof]) -> String {
+    let tree = VexMerkleTree::from_leaves(round_equivocation_leaves(output_rounds, equivocations));
+    tree.root_hash().map(|h| h.to_hex()).unwrap_or_else(|| VexHash::digest(b"empty").to_hex())
+}
+
+/// Spawn `config.num_verifiers` independent Shadow agents, each with a
+/// slightly different `challenge_intensity` (jittered around the base
+/// aggressive/non-aggressive value), and collect one `Vote` per verifier
+/// against `response`. A verifier "agrees" if it raises no issues.
+async fn spawn_verifier_panel(
+    bridge: &VexProviderBridge<'_>,
+    blue_agent: &Agent,
+    config: &VexDebateConfig,
+    response: &str,
+) -> Result<Vec<Vote>> {
+    let base_intensity = if config.aggressive_mode { 0.9 } else { 0.7 };
+    let n = config.num_verifiers.max(1);
+    let mut votes = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let jitter = (i as f64 - (n as f64 - 1.0) / 2.0) * 0.05;
+        let challenge_intensity = (base_intensity + jitter).clamp(0.1, 0.99);
+        let verifier = ShadowAgent::new(
+            blue_agent,
+            ShadowConfig {
+                challenge_intensity,
+                fact_check: true,
+                logic_check: true,
+            },
+        );
+
+        let issues = verifier.detect_issues(response);
+        let challenge_prompt = verifier.challenge_prompt(response);
+        let challenge = bridge
+            .ask(&challenge_prompt)
+            .await
+            .map_err(|e| anyhow::anyhow!("Verifier {} failed: {}", i, e))?;
+
+        let agrees = issues.is_empty();
+        let confidence = if agrees {
+            1.0 - challenge_intensity * 0.2
+        } else {
+            1.0 - challenge_intensity * 0.2 - 0.3
+        }
+        .clamp(0.0, 1.0);
+
+        votes.push(Vote {
+            agent_id: verifier.agent.id,
+            agrees,
+            confidence,
+            reasoning: if challenge.trim().is_empty() {
+                None
+            } else {
+                Some(challenge)
+            },
+        });
+    }
+
+    Ok(votes)
+}
+
+/// Combine a verifier panel's votes into an accept/reject decision and an
+/// aggregate confidence, per `mode`. Returns `(upheld, aggregate_confidence)`.
+fn evaluate_consensus(votes: &[Vote], mode: ConsensusMode, confidence_threshold: f64) -> (bool, f64) {
+    if votes.is_empty() {
+        return (false, 0.0);
+    }
+
+    let n = votes.len();
+    let agree_count = votes.iter().filter(|v| v.agrees).count();
+    let aggregate_confidence = votes.iter().map(|v| v.confidence).sum::<f64>() / n as f64;
+
+    let upheld = match mode {
+        ConsensusMode::SimpleMajority => agree_count * 2 > n,
+        ConsensusMode::WeightedConfidence => aggregate_confidence >= confidence_threshold,
+        ConsensusMode::ByzantineSupermajority => {
+            let required = (2 * n + 2) / 3; // ceil(2n/3)
+            agree_count >= required && aggregate_confidence >= confidence_threshold
+        }
+        ConsensusMode::Unanimous => agree_count == n,
+    };
+
+    (upheld, aggregate_confidence)
+}
+
+/// Does claim `a` contradict claim `b` (can they not both be true)? Asked
+/// directly of the provider rather than pattern-matched, since contradiction
+/// is a meaning-level judgment surface wording can't reliably capture.
+async fn claims_contradict(bridge: &VexProviderBridge<'_>, a: &str, b: &str) -> Result<bool> {
+    let prompt = format!(
+        "Claim A: {}\nClaim B: {}\n\nAre these two claims mutually inconsistent (can they not both be true)? \
+         Answer with exactly one word: yes or no.",
+        a, b
+    );
+    let response = bridge
+        .ask(&prompt)
+        .await
+        .map_err(|e| anyhow::anyhow!("Contradiction check failed: {}", e))?;
+    Ok(response.to_lowercase().split_whitespace().any(|w| w == "yes"))
+}
+
+/// Sample `k` independent responses to `prompt` from the provider. The
+/// current `LlmProvider`/`VexLlmProvider` traits don't expose a temperature
+/// knob, so this relies on the provider's own default sampling
+/// non-determinism rather than forcing a specific nonzero temperature.
+async fn sample_responses(bridge: &VexProviderBridge<'_>, prompt: &str, k: usize) -> Result<Vec<String>> {
+    let mut samples = Vec::with_capacity(k);
+    for _ in 0..k {
+        let response = bridge
+            .ask(prompt)
+            .await
+            .map_err(|e| anyhow::anyhow!("Semantic-entropy sampling failed: {}", e))?;
+        samples.push(response);
+    }
+    Ok(samples)
+}
+
+/// Bidirectional NLI entailment check via an LLM prompt: `a` and `b` are
+/// considered equivalent in meaning only if each entails the other
+async fn entails_both_ways(bridge: &VexProviderBridge<'_>, a: &str, b: &str) -> Result<bool> {
+    let nli_prompt = format!(
+        "Statement A: {}\nStatement B: {}\n\nDoes A entail B (if A is true, must B also be true)? Does B entail A? \
+         Answer with exactly two words, each \"yes\" or \"no\", in the form \"<A-entails-B> <B-entails-A>\".",
+        a, b
+    );
+    let response = bridge
+        .ask(&nli_prompt)
+        .await
+        .map_err(|e| anyhow::anyhow!("NLI entailment check failed: {}", e))?;
+    let answers: Vec<&str> = response
+        .to_lowercase()
+        .split_whitespace()
+        .filter(|w| *w == "yes" || *w == "no")
+        .collect();
+    Ok(answers.len() >= 2 && answers[0] == "yes" && answers[1] == "yes")
+}
+
+/// Greedily cluster `samples` by meaning: the first sample starts its own
+/// cluster, and each subsequent sample joins the first existing cluster
+/// whose representative entails it both ways, else starts a new cluster.
+/// Returns the cluster id assigned to each sample, in order.
+async fn cluster_by_meaning(bridge: &VexProviderBridge<'_>, samples: &[String]) -> Result<Vec<usize>> {
+    let mut representatives: Vec<&str> = Vec::new();
+    let mut cluster_ids = Vec::with_capacity(samples.len());
+
+    for sample in samples {
+        let mut assigned = None;
+        for (idx, representative) in representatives.iter().enumerate() {
+            if entails_both_ways(bridge, sample, representative).await? {
+                assigned = Some(idx);
+                break;
+            }
+        }
+        match assigned {
+            Some(idx) => cluster_ids.push(idx),
+            None => {
+                representatives.push(sample.as_str());
+                cluster_ids.push(representatives.len() - 1);
+            }
+        }
+    }
+
+    Ok(cluster_ids)
+}
+
+/// Shannon entropy H = -Σ p_c·log(p_c) over cluster membership proportions
+fn semantic_entropy_of_clusters(cluster_ids: &[usize]) -> f64 {
+    if cluster_ids.is_empty() {
+        return 0.0;
+    }
+    let k = cluster_ids.len() as f64;
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for &id in cluster_ids {
+        *counts.entry(id).or_insert(0) += 1;
+    }
+    -counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / k;
+            p * p.ln()
+        })
+        .sum::<f64>()
+}
+
 /// Assess how strong a rebuttal is (0.0 = weak, 1.0 = strong)
 fn assess_rebuttal_strength(rebuttal: &str) -> f64 {
     let rebuttal_lower = rebuttal.to_lowercase();
@@ -2,10 +2,15 @@
 
 use std::path::PathBuf;
 
-/// Get the path to the test dataset
+#[path = "../src/dataset_fetch.rs"]
+mod dataset_fetch;
+
+/// Get the path to the test dataset, fetching it on demand (when opted in)
+/// if it isn't present in the local checkout.
 fn get_dataset_path() -> PathBuf {
-    // When running tests, we're in the project root
-    PathBuf::from("datasets/vex_halt")
+    let configured = PathBuf::from("datasets/vex_halt");
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    dataset_fetch::resolve_dataset_path(&configured, &manifest_dir).unwrap_or(configured)
 }
 
 #[cfg(test)]